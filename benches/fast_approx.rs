@@ -0,0 +1,72 @@
+//! Benchmarks das aproximações rápidas contra as versões precisas.
+//!
+//! Sem dependências: `cargo bench` mede cada função sobre um mesmo
+//! conjunto de entradas e imprime o custo médio por chamada.
+
+use std::hint::black_box;
+use std::time::Instant;
+
+use rdsmath::*;
+
+const N: usize = 1 << 20;
+const ROUNDS: usize = 5;
+
+// Menor tempo médio por chamada entre as rodadas, em ns
+fn bench(name: &str, inputs: &[f32], f: impl Fn(f32) -> f32) {
+    let mut best = f64::INFINITY;
+    for _ in 0..ROUNDS {
+        let start = Instant::now();
+        let mut acc = 0.0;
+        for &x in inputs {
+            acc += f(black_box(x));
+        }
+        black_box(acc);
+        best = best.min(start.elapsed().as_nanos() as f64 / inputs.len() as f64);
+    }
+    println!("{name:<16} {best:>7.2} ns/op");
+}
+
+// Como `bench`, mas para uma função que processa a slice inteira
+fn bench_slice(name: &str, inputs: &[f32], f: impl Fn(&[f32], &mut [f32], &mut [f32])) {
+    let mut a = vec![0.0; inputs.len()];
+    let mut b = vec![0.0; inputs.len()];
+    let mut best = f64::INFINITY;
+    for _ in 0..ROUNDS {
+        let start = Instant::now();
+        f(black_box(inputs), &mut a, &mut b);
+        black_box((&a, &b));
+        best = best.min(start.elapsed().as_nanos() as f64 / inputs.len() as f64);
+    }
+    println!("{name:<16} {best:>7.2} ns/op");
+}
+
+// N valores igualmente espaçados em [lo, hi)
+fn inputs(lo: f32, hi: f32) -> Vec<f32> {
+    (0..N)
+        .map(|i| lo + (hi - lo) * i as f32 / N as f32)
+        .collect()
+}
+
+fn main() {
+    let exp_in = inputs(-10.0, 10.0);
+    bench("expf", &exp_in, expf);
+    bench("expf_fast", &exp_in, expf_fast);
+
+    // Curva de gama típica: expoente fixo, base em [0, 1)
+    let unit_in = inputs(0.0, 1.0);
+    bench("powf", &unit_in, |x| powf(x, 0.45));
+    bench("powf_fast", &unit_in, |x| powf_fast(x, 0.45));
+
+    let angle_in = inputs(-100.0, 100.0);
+    bench_slice("sincosf loop", &angle_in, |angles, s, c| {
+        for i in 0..angles.len() {
+            (s[i], c[i]) = sincosf(angles[i]);
+        }
+    });
+    bench_slice("sincos_slicef", &angle_in, sincos_slicef);
+
+    // Faixa útil de ativações
+    let tanh_in = inputs(-5.0, 5.0);
+    bench("tanhf", &tanh_in, tanhf);
+    bench("tanhf_fast", &tanh_in, tanhf_fast);
+}
@@ -0,0 +1,84 @@
+//! # Comparação Aproximada
+//!
+//! Tolerâncias padrão da crate e o trait [`ApproxEq`].
+//! Centraliza os epsilons usados nas comparações e guardas internas.
+
+// =============================================================================
+// TOLERÂNCIAS
+// =============================================================================
+
+/// Tolerância padrão para f32 (~8 × `f32::EPSILON`).
+pub const DEFAULT_EPSILON_F32: f32 = 1e-6;
+
+/// Tolerância padrão para f64 (~4.5 × `f64::EPSILON`).
+pub const DEFAULT_EPSILON_F64: f64 = 1e-15;
+
+/// Tolerância frouxa para f32, para valores que já passaram por
+/// aproximações (ex.: detectar expoente inteiro em `powf`).
+pub const LOOSE_EPSILON_F32: f32 = 1e-4;
+
+// =============================================================================
+// TRAIT
+// =============================================================================
+
+/// Comparação aproximada com tolerância absoluta.
+pub trait ApproxEq: Copy {
+    /// Tolerância usada por [`ApproxEq::approx_eq_default`].
+    const DEFAULT_EPSILON: Self;
+
+    /// Verdadeiro se `|self - other| < epsilon`.
+    fn approx_eq_eps(self, other: Self, epsilon: Self) -> bool;
+
+    /// Compara com a tolerância padrão do tipo.
+    #[inline]
+    fn approx_eq_default(self, other: Self) -> bool {
+        self.approx_eq_eps(other, Self::DEFAULT_EPSILON)
+    }
+}
+
+impl ApproxEq for f32 {
+    const DEFAULT_EPSILON: f32 = DEFAULT_EPSILON_F32;
+
+    #[inline]
+    fn approx_eq_eps(self, other: f32, epsilon: f32) -> bool {
+        let diff = self - other;
+        (if diff < 0.0 { -diff } else { diff }) < epsilon
+    }
+}
+
+impl ApproxEq for f64 {
+    const DEFAULT_EPSILON: f64 = DEFAULT_EPSILON_F64;
+
+    #[inline]
+    fn approx_eq_eps(self, other: f64, epsilon: f64) -> bool {
+        let diff = self - other;
+        (if diff < 0.0 { -diff } else { diff }) < epsilon
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_epsilons_match_machine_epsilon_scale() {
+        // Poucas unidades de arredondamento, nem mais nem menos
+        let r32 = DEFAULT_EPSILON_F32 / f32::EPSILON;
+        let r64 = DEFAULT_EPSILON_F64 / f64::EPSILON;
+        assert!((1.0..16.0).contains(&r32), "f32: {}", r32);
+        assert!((1.0..16.0).contains(&r64), "f64: {}", r64);
+        assert_eq!(<f32 as ApproxEq>::DEFAULT_EPSILON, DEFAULT_EPSILON_F32);
+        assert_eq!(<f64 as ApproxEq>::DEFAULT_EPSILON, DEFAULT_EPSILON_F64);
+        const { assert!(LOOSE_EPSILON_F32 > DEFAULT_EPSILON_F32) };
+    }
+
+    #[test]
+    fn approx_eq_uses_absolute_tolerance() {
+        assert!(1.0f32.approx_eq_default(1.0 + 5e-7));
+        assert!(!1.0f32.approx_eq_default(1.0 + 2e-6));
+        assert!(1.0f64.approx_eq_default(1.0 + 4.0 * f64::EPSILON));
+        assert!(!1.0f64.approx_eq_default(1.0 + 1e-14));
+        assert!(2.0f32.approx_eq_eps(2.1, 0.2));
+        assert!(!f32::NAN.approx_eq_default(f32::NAN));
+    }
+}
@@ -0,0 +1,125 @@
+//! # Áudio
+//!
+//! Conversões de amostras PCM e utilitários de dinâmica.
+
+use crate::round::roundf;
+use crate::util::{clampf, maxf, sqf};
+
+// =============================================================================
+// PCM
+// =============================================================================
+
+/// Converte amostra PCM i16 para float normalizado.
+///
+/// Divide por 32768: `i16::MIN` vira exatamente -1.0 e `i16::MAX`
+/// vira 32767/32768 (a faixa do i16 é assimétrica).
+#[inline]
+pub fn i16_to_normf(s: i16) -> f32 {
+    s as f32 * (1.0 / 32768.0)
+}
+
+/// Converte float normalizado para amostra PCM i16, com saturação.
+///
+/// Usa a mesma escala de 32768 de `i16_to_normf`, então a ida e volta
+/// é exata para todo i16. Como +1.0 * 32768 não cabe em i16, o lado
+/// positivo satura em `i16::MAX`; -1.0 vira `i16::MIN`. NaN vira 0.
+#[inline]
+pub fn norm_to_i16f(x: f32) -> i16 {
+    if x.is_nan() {
+        return 0;
+    }
+
+    let v = roundf(clampf(x, -1.0, 1.0) * 32768.0);
+    clampf(v, i16::MIN as f32, i16::MAX as f32) as i16
+}
+
+// =============================================================================
+// DINÂMICA
+// =============================================================================
+
+/// Curva de ganho de um compressor com joelho suave (em dB).
+///
+/// Retorna a redução de ganho (≤ 0 dB) para o nível de entrada: zero
+/// abaixo do joelho, `(1/ratio - 1) * (input - threshold)` acima dele e
+/// uma transição quadrática de largura `knee_db` centrada no limiar.
+/// `knee_db <= 0` dá joelho duro; `ratio` abaixo de 1 é tratado como 1.
+#[inline]
+pub fn compressor_gainf(input_db: f32, threshold_db: f32, ratio: f32, knee_db: f32) -> f32 {
+    let slope = 1.0 / maxf(ratio, 1.0) - 1.0;
+    let over = input_db - threshold_db;
+
+    if knee_db <= 0.0 {
+        return slope * maxf(over, 0.0);
+    }
+
+    // Dentro do joelho: slope * (over + k/2)² / 2k; acima dele a parte
+    // quadrática vale k/2 e o restante cresce linearmente
+    let half = 0.5 * knee_db;
+    let x = clampf(over + half, 0.0, knee_db);
+
+    slope * (sqf(x) / (2.0 * knee_db) + maxf(over - half, 0.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn i16_round_trip_is_exact() {
+        for s in i16::MIN..=i16::MAX {
+            assert_eq!(norm_to_i16f(i16_to_normf(s)), s);
+        }
+    }
+
+    #[test]
+    fn i16_boundaries_saturate() {
+        assert_eq!(i16_to_normf(i16::MIN), -1.0);
+        assert_eq!(i16_to_normf(i16::MAX), 32767.0 / 32768.0);
+        assert_eq!(norm_to_i16f(1.0), i16::MAX);
+        assert_eq!(norm_to_i16f(-1.0), i16::MIN);
+        assert_eq!(norm_to_i16f(5.0), i16::MAX);
+        assert_eq!(norm_to_i16f(-5.0), i16::MIN);
+        assert_eq!(norm_to_i16f(f32::INFINITY), i16::MAX);
+        assert_eq!(norm_to_i16f(f32::NEG_INFINITY), i16::MIN);
+        assert_eq!(norm_to_i16f(f32::NAN), 0);
+        assert_eq!(norm_to_i16f(0.0), 0);
+    }
+
+    #[test]
+    fn compressor_gain_regions() {
+        let (threshold, ratio, knee) = (-20.0, 4.0, 6.0);
+        // Abaixo do joelho: sem redução
+        for input in [-60.0, -30.0, -23.0] {
+            assert_eq!(compressor_gainf(input, threshold, ratio, knee), 0.0);
+        }
+        // Bem acima: segue a razão, (1/4 - 1) * excesso
+        for over in [3.0, 10.0, 40.0] {
+            let g = compressor_gainf(threshold + over, threshold, ratio, knee);
+            assert!((g - (0.25 - 1.0) * over).abs() < 1e-5, "over = {}", over);
+        }
+        // Joelho duro e razão 1
+        assert_eq!(compressor_gainf(-10.0, threshold, ratio, 0.0), -7.5);
+        assert_eq!(compressor_gainf(-15.0, -20.0, 4.0, 0.0), -3.75);
+        assert_eq!(compressor_gainf(0.0, threshold, 1.0, knee), 0.0);
+    }
+
+    #[test]
+    fn compressor_knee_is_continuous() {
+        let (threshold, ratio, knee) = (-20.0, 4.0, 6.0);
+        let g = |x| compressor_gainf(x, threshold, ratio, knee);
+        let mut prev = g(-30.0);
+        let step = 0.001;
+        let mut x = -30.0;
+        while x < -10.0 {
+            x += step;
+            let v = g(x);
+            // Sem saltos e nunca aumenta o ganho
+            assert!((v - prev).abs() <= 0.75 * step + 1e-5, "salto em {}", x);
+            assert!(v <= prev + 1e-6);
+            prev = v;
+        }
+        // Nas bordas do joelho os dois lados coincidem
+        assert!(g(-23.0).abs() < 1e-6);
+        assert!((g(-17.0) - (-0.75 * 3.0)).abs() < 1e-5);
+    }
+}
@@ -0,0 +1,222 @@
+//! # Cores
+//!
+//! Gradientes, conversões de cor e iluminação.
+
+use crate::util::{inv_lerpf, lerpf, saturatef, sqf};
+
+// Curvas de transferência sRGB, definidas em util
+pub use crate::util::{linear_to_srgbf, srgb_to_linearf};
+
+// =============================================================================
+// GRADIENTES
+// =============================================================================
+
+/// Amostra um gradiente RGB definido por paradas `(posição, cor)`.
+///
+/// As posições devem estar em ordem crescente. Fora do intervalo,
+/// retorna a cor da parada da ponta. Slice vazia retorna preto.
+#[inline]
+pub fn gradient_samplef(stops: &[(f32, [f32; 3])], t: f32) -> [f32; 3] {
+    let (first, last) = match (stops.first(), stops.last()) {
+        (Some(f), Some(l)) => (f, l),
+        _ => return [0.0; 3],
+    };
+
+    if t <= first.0 {
+        return first.1;
+    }
+    if t >= last.0 {
+        return last.1;
+    }
+
+    // Procura o par de paradas que contém t
+    for pair in stops.windows(2) {
+        let (p0, c0) = pair[0];
+        let (p1, c1) = pair[1];
+        if t <= p1 {
+            let u = inv_lerpf(p0, p1, t);
+            return [
+                lerpf(c0[0], c1[0], u),
+                lerpf(c0[1], c1[1], u),
+                lerpf(c0[2], c1[2], u),
+            ];
+        }
+    }
+
+    last.1
+}
+
+// =============================================================================
+// ILUMINAÇÃO
+// =============================================================================
+
+/// Atenuação de luz pontual: 1 / (constant + linear·d + quadratic·d²).
+///
+/// Com `constant >= 1` o resultado fica em (0, 1], inclusive em d = 0.
+/// Se o denominador não for positivo, retorna 1 em vez de infinito.
+#[inline]
+pub fn light_attenuationf(distance: f32, constant: f32, linear: f32, quadratic: f32) -> f32 {
+    let denom = constant + linear * distance + quadratic * sqf(distance);
+    if denom <= 0.0 {
+        return 1.0;
+    }
+    1.0 / denom
+}
+
+/// Atenuação quadrática inversa com corte suave no raio da luz.
+///
+/// `saturate(1 - (d/r)⁴)² / (d² + 1)`: vale 1 em d = 0, decresce com a
+/// distância e chega a exatamente 0 em `radius` (e além).
+#[inline]
+pub fn inverse_square_attenuationf(distance: f32, radius: f32) -> f32 {
+    if radius <= 0.0 {
+        return 0.0;
+    }
+
+    let ratio2 = sqf(distance / radius);
+    let window = sqf(saturatef(1.0 - sqf(ratio2)));
+    window / (sqf(distance) + 1.0)
+}
+
+// =============================================================================
+// sRGB
+// =============================================================================
+
+/// Converte um canal sRGB de 8 bits para linear via tabela.
+///
+/// Muito mais barato que `srgb_to_linearf` para texturas de 8 bits.
+#[inline]
+pub const fn srgb8_to_linearf(byte: u8) -> f32 {
+    SRGB_DECODE_LUT[byte as usize]
+}
+
+/// Tabela sRGB (8 bits) → linear, gerada em tempo de compilação.
+pub const SRGB_DECODE_LUT: [f32; 256] = build_srgb_decode_lut();
+
+const fn build_srgb_decode_lut() -> [f32; 256] {
+    let mut table = [0.0; 256];
+    let mut i = 0;
+    while i < 256 {
+        let c = i as f64 / 255.0;
+        let linear = if c <= 0.04045 {
+            c / 12.92
+        } else {
+            // y^2.4 = y² · (y²)^(1/5)
+            let y = (c + 0.055) / 1.055;
+            let y2 = y * y;
+            y2 * fifth_root_f64(y2)
+        };
+        table[i] = linear as f32;
+        i += 1;
+    }
+    table
+}
+
+// Raiz quinta por Newton-Raphson, para a em (0, 1]
+const fn fifth_root_f64(a: f64) -> f64 {
+    let mut r = 1.0;
+    let mut i = 0;
+    while i < 40 {
+        let r4 = r * r * r * r;
+        r -= (r4 * r - a) / (5.0 * r4);
+        i += 1;
+    }
+    r
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const STOPS: [(f32, [f32; 3]); 3] = [
+        (0.0, [0.0, 0.0, 1.0]),
+        (0.5, [0.0, 1.0, 0.0]),
+        (1.0, [1.0, 0.0, 0.0]),
+    ];
+
+    #[test]
+    fn gradient_on_stop_returns_stop_color() {
+        for (pos, color) in STOPS {
+            assert_eq!(gradient_samplef(&STOPS, pos), color);
+        }
+    }
+
+    #[test]
+    fn gradient_between_stops_interpolates() {
+        let c = gradient_samplef(&STOPS, 0.25);
+        assert!((c[0] - 0.0).abs() < 1e-6);
+        assert!((c[1] - 0.5).abs() < 1e-6);
+        assert!((c[2] - 0.5).abs() < 1e-6);
+
+        let c = gradient_samplef(&STOPS, 0.875);
+        assert!((c[0] - 0.75).abs() < 1e-6);
+        assert!((c[1] - 0.25).abs() < 1e-6);
+        assert!((c[2] - 0.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn gradient_clamps_beyond_ends() {
+        assert_eq!(gradient_samplef(&STOPS, -3.0), STOPS[0].1);
+        assert_eq!(gradient_samplef(&STOPS, 7.0), STOPS[2].1);
+    }
+
+    #[test]
+    fn gradient_degenerate_slices() {
+        assert_eq!(gradient_samplef(&[], 0.5), [0.0; 3]);
+        let single = [(0.3, [0.2, 0.4, 0.6])];
+        assert_eq!(gradient_samplef(&single, -1.0), single[0].1);
+        assert_eq!(gradient_samplef(&single, 0.3), single[0].1);
+        assert_eq!(gradient_samplef(&single, 2.0), single[0].1);
+    }
+
+    #[test]
+    fn light_attenuation_decreases_with_distance() {
+        let (c, l, q) = (1.0, 0.09, 0.032);
+        assert_eq!(light_attenuationf(0.0, c, l, q), 1.0);
+        let mut prev = 1.0;
+        for i in 1..=100 {
+            let a = light_attenuationf(i as f32 * 0.5, c, l, q);
+            assert!(a < prev && a > 0.0, "d = {}", i as f32 * 0.5);
+            prev = a;
+        }
+        // Denominador nulo não gera infinito
+        assert_eq!(light_attenuationf(0.0, 0.0, 0.0, 0.0), 1.0);
+    }
+
+    #[test]
+    fn inverse_square_attenuation_reaches_zero_at_radius() {
+        let r = 10.0;
+        assert_eq!(inverse_square_attenuationf(0.0, r), 1.0);
+        let mut prev = 1.0;
+        for i in 1..100 {
+            let a = inverse_square_attenuationf(i as f32 * 0.1, r);
+            assert!(a < prev && a > 0.0, "d = {}", i as f32 * 0.1);
+            prev = a;
+        }
+        assert_eq!(inverse_square_attenuationf(r, r), 0.0);
+        assert_eq!(inverse_square_attenuationf(15.0, r), 0.0);
+        assert_eq!(inverse_square_attenuationf(1.0, 0.0), 0.0);
+    }
+
+    #[test]
+    fn srgb_lut_matches_analytic_decode() {
+        for b in [0u8, 1, 10, 11, 64, 128, 188, 200, 254, 255] {
+            let c = b as f32 / 255.0;
+            let lut = srgb8_to_linearf(b);
+            assert!((lut - srgb_to_linearf(c)).abs() < 1e-5, "byte {}", b);
+        }
+        assert_eq!(srgb8_to_linearf(0), 0.0);
+        assert_eq!(srgb8_to_linearf(255), 1.0);
+
+        // Tabela inteira contra a curva em f64
+        for (i, &v) in SRGB_DECODE_LUT.iter().enumerate() {
+            let c = i as f64 / 255.0;
+            let reference = if c <= 0.04045 {
+                c / 12.92
+            } else {
+                ((c + 0.055) / 1.055).powf(2.4)
+            };
+            assert!((v as f64 - reference).abs() < 1e-7, "entrada {}", i);
+        }
+    }
+}
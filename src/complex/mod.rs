@@ -0,0 +1,167 @@
+//! # Números Complexos
+//!
+//! Tipo complexo em f32 para DSP e geometria 2D.
+
+use core::ops::{Add, Mul, Neg, Sub};
+
+use crate::exp::hypotf;
+use crate::trig::{atan2f, sincosf};
+
+// =============================================================================
+// COMPLEX32
+// =============================================================================
+
+/// Número complexo `re + im·i` em f32.
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub struct Complex32 {
+    pub re: f32,
+    pub im: f32,
+}
+
+impl Complex32 {
+    /// Zero.
+    pub const ZERO: Complex32 = Complex32::new(0.0, 0.0);
+
+    /// Unidade real.
+    pub const ONE: Complex32 = Complex32::new(1.0, 0.0);
+
+    /// Unidade imaginária.
+    pub const I: Complex32 = Complex32::new(0.0, 1.0);
+
+    /// Cria um complexo a partir das partes real e imaginária.
+    #[inline]
+    pub const fn new(re: f32, im: f32) -> Self {
+        Self { re, im }
+    }
+
+    /// Cria a partir da forma polar `r·e^(iθ)`.
+    #[inline]
+    pub fn from_polar(r: f32, theta: f32) -> Complex32 {
+        let (s, c) = sincosf(theta);
+        Complex32::new(r * c, r * s)
+    }
+
+    /// Conjugado.
+    #[inline]
+    pub const fn conj(self) -> Complex32 {
+        Complex32::new(self.re, -self.im)
+    }
+
+    /// Módulo ao quadrado (evita a raiz).
+    #[inline]
+    pub fn norm_sqr(self) -> f32 {
+        self.re * self.re + self.im * self.im
+    }
+
+    /// Módulo |z|.
+    #[inline]
+    pub fn abs(self) -> f32 {
+        hypotf(self.re, self.im)
+    }
+
+    /// Argumento (ângulo) em [-π, π].
+    #[inline]
+    pub fn arg(self) -> f32 {
+        atan2f(self.im, self.re)
+    }
+
+    /// Multiplica as duas partes por um escalar.
+    #[inline]
+    pub fn scale(self, s: f32) -> Complex32 {
+        Complex32::new(self.re * s, self.im * s)
+    }
+}
+
+impl Add for Complex32 {
+    type Output = Complex32;
+
+    #[inline]
+    fn add(self, rhs: Complex32) -> Complex32 {
+        Complex32::new(self.re + rhs.re, self.im + rhs.im)
+    }
+}
+
+impl Sub for Complex32 {
+    type Output = Complex32;
+
+    #[inline]
+    fn sub(self, rhs: Complex32) -> Complex32 {
+        Complex32::new(self.re - rhs.re, self.im - rhs.im)
+    }
+}
+
+impl Mul for Complex32 {
+    type Output = Complex32;
+
+    #[inline]
+    fn mul(self, rhs: Complex32) -> Complex32 {
+        Complex32::new(
+            self.re * rhs.re - self.im * rhs.im,
+            self.re * rhs.im + self.im * rhs.re,
+        )
+    }
+}
+
+impl Neg for Complex32 {
+    type Output = Complex32;
+
+    #[inline]
+    fn neg(self) -> Complex32 {
+        Complex32::new(-self.re, -self.im)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::consts::PI;
+
+    fn close(a: Complex32, b: Complex32, tol: f32) -> bool {
+        (a.re - b.re).abs() < tol && (a.im - b.im).abs() < tol
+    }
+
+    #[test]
+    fn i_squared_is_minus_one() {
+        assert_eq!(Complex32::I * Complex32::I, -Complex32::ONE);
+        assert_eq!(Complex32::ONE * Complex32::I, Complex32::I);
+
+        // (1 + 2i)(3 - i) = 5 + 5i
+        let p = Complex32::new(1.0, 2.0) * Complex32::new(3.0, -1.0);
+        assert_eq!(p, Complex32::new(5.0, 5.0));
+        assert_eq!(p + Complex32::new(1.0, -2.0), Complex32::new(6.0, 3.0));
+        assert_eq!(p - p, Complex32::ZERO);
+        assert_eq!(p.scale(0.5), Complex32::new(2.5, 2.5));
+    }
+
+    #[test]
+    fn polar_round_trip() {
+        for &(r, theta) in &[
+            (1.0, 0.0),
+            (2.5, 0.7),
+            (0.3, -2.0),
+            (10.0, 3.0),
+            (4.0, -PI / 2.0),
+        ] {
+            let z = Complex32::from_polar(r, theta);
+            assert!((z.abs() - r).abs() < 1e-5 * r.max(1.0), "|z| = {}", z.abs());
+            assert!((z.arg() - theta).abs() < 1e-5, "arg = {}", z.arg());
+            assert!((z.norm_sqr() - r * r).abs() < 1e-4 * r * r);
+            assert!(close(
+                Complex32::from_polar(z.abs(), z.arg()),
+                z,
+                1e-5 * r.max(1.0)
+            ));
+        }
+        assert!((Complex32::new(-3.0, 4.0).abs() - 5.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn conj_negates_imaginary_part() {
+        let z = Complex32::new(3.0, -4.0);
+        assert_eq!(z.conj(), Complex32::new(3.0, 4.0));
+        assert_eq!(z.conj().conj(), z);
+        // z · conj(z) = |z|² real
+        assert_eq!(z * z.conj(), Complex32::new(z.norm_sqr(), 0.0));
+        assert!((z.conj().arg() + z.arg()).abs() < 1e-6);
+    }
+}
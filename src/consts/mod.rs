@@ -64,3 +64,37 @@ pub fn deg_to_rad(deg: f32) -> f32 {
 pub fn rad_to_deg(rad: f32) -> f32 {
     rad * RAD_TO_DEG
 }
+
+// =============================================================================
+// F64 VERSIONS
+// =============================================================================
+
+/// π em f64.
+pub const PI_F64: f64 = 3.14159265358979323846264338327950288;
+
+/// τ = 2π em f64.
+pub const TAU_F64: f64 = 6.28318530717958647692528676655900577;
+
+/// π/2 em f64.
+pub const FRAC_PI_2_F64: f64 = 1.57079632679489661923132169163975144;
+
+/// π/4 em f64.
+pub const FRAC_PI_4_F64: f64 = 0.785398163397448309615660845819875721;
+
+/// e em f64.
+pub const E_F64: f64 = 2.71828182845904523536028747135266250;
+
+/// log₂(e) em f64.
+pub const LOG2_E_F64: f64 = 1.44269504088896340735992468100189214;
+
+/// log₁₀(e) em f64.
+pub const LOG10_E_F64: f64 = 0.434294481903251827651128918916605082;
+
+/// ln(2) em f64.
+pub const LN_2_F64: f64 = 0.693147180559945309417232121458176568;
+
+/// ln(10) em f64.
+pub const LN_10_F64: f64 = 2.30258509299404568401799145468436421;
+
+/// √2 em f64.
+pub const SQRT_2_F64: f64 = 1.41421356237309504880168872420969808;
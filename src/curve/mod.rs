@@ -0,0 +1,381 @@
+//! # Curvas
+//!
+//! Splines e curvas paramétricas para animação.
+
+use crate::round::isnanf;
+use crate::util::{lerpf, smoothstepf, stepf};
+
+// =============================================================================
+// HERMITE
+// =============================================================================
+
+/// Spline cúbica de Hermite com tangentes explícitas.
+///
+/// Passa por `p0` em t=0 com derivada `m0` e por `p1` em t=1 com
+/// derivada `m1`.
+#[inline]
+pub fn hermitef(p0: f32, m0: f32, p1: f32, m1: f32, t: f32) -> f32 {
+    let t2 = t * t;
+    let t3 = t2 * t;
+
+    // Bases de Hermite
+    let h00 = 2.0 * t3 - 3.0 * t2 + 1.0;
+    let h10 = t3 - 2.0 * t2 + t;
+    let h01 = -2.0 * t3 + 3.0 * t2;
+    let h11 = t3 - t2;
+
+    h00 * p0 + h10 * m0 + h01 * p1 + h11 * m1
+}
+
+// =============================================================================
+// BEZIER
+// =============================================================================
+
+/// Bezier cúbica com pontos de controle p0..p3.
+#[inline]
+pub fn cubic_bezierf(p0: f32, p1: f32, p2: f32, p3: f32, t: f32) -> f32 {
+    let u = 1.0 - t;
+    let uu = u * u;
+    let tt = t * t;
+
+    uu * u * p0 + 3.0 * uu * t * p1 + 3.0 * u * tt * p2 + tt * t * p3
+}
+
+/// Derivada da Bezier cúbica em relação a t (tangente da curva).
+///
+/// É uma Bezier quadrática sobre as diferenças dos pontos de controle.
+#[inline]
+pub fn cubic_bezier_tangentf(p0: f32, p1: f32, p2: f32, p3: f32, t: f32) -> f32 {
+    let u = 1.0 - t;
+
+    3.0 * (u * u * (p1 - p0) + 2.0 * u * t * (p2 - p1) + t * t * (p3 - p2))
+}
+
+// =============================================================================
+// KEYFRAMES
+// =============================================================================
+
+/// Modo de interpolação entre keyframes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum InterpMode {
+    /// Interpolação linear.
+    #[default]
+    Linear,
+    /// Mantém o valor do keyframe anterior até o próximo.
+    Step,
+    /// Smoothstep (derivada zero nos keyframes).
+    Smooth,
+}
+
+/// Amostra uma curva de keyframes no tempo `t`.
+///
+/// `times` deve estar em ordem crescente; pares além do menor dos dois
+/// comprimentos são ignorados. Antes do primeiro keyframe retorna o
+/// primeiro valor e depois do último, o último. Sem keyframes retorna 0;
+/// `t` NaN retorna NaN.
+#[inline]
+pub fn sample_curvef(times: &[f32], values: &[f32], t: f32, mode: InterpMode) -> f32 {
+    let n = times.len().min(values.len());
+    if n == 0 {
+        return 0.0;
+    }
+    // NaN falha as duas comparações abaixo e quebraria a busca do segmento
+    if isnanf(t) {
+        return f32::NAN;
+    }
+    if t <= times[0] {
+        return values[0];
+    }
+    if t >= times[n - 1] {
+        return values[n - 1];
+    }
+
+    // times[i] <= t < times[i + 1]
+    let i = times[..n].partition_point(|&k| k <= t) - 1;
+    let (t0, t1) = (times[i], times[i + 1]);
+    let (a, b) = (values[i], values[i + 1]);
+
+    match mode {
+        InterpMode::Linear => lerpf(a, b, (t - t0) / (t1 - t0)),
+        InterpMode::Step => lerpf(a, b, stepf(t1, t)),
+        InterpMode::Smooth => lerpf(a, b, smoothstepf(t0, t1, t)),
+    }
+}
+
+/// Amostra uma curva de keyframes com interpolação linear.
+///
+/// Atalho para [`sample_curvef`] com [`InterpMode::Linear`].
+#[inline]
+pub fn lerp_curvef(times: &[f32], values: &[f32], t: f32) -> f32 {
+    sample_curvef(times, values, t, InterpMode::Linear)
+}
+
+// =============================================================================
+// SPLINE CÚBICA
+// =============================================================================
+
+/// Condição de contorno de [`CubicSpline`].
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub enum SplineBoundary {
+    /// Segunda derivada zero nas pontas (spline natural).
+    #[default]
+    Natural,
+    /// Primeira derivada fixa na primeira e na última ponta.
+    Clamped(f32, f32),
+}
+
+/// Spline cúbica interpolante com N nós, contínua até a segunda derivada.
+///
+/// Ao contrário de Catmull-Rom, as tangentes não são locais: saem de um
+/// sistema tridiagonal resolvido na construção (algoritmo de Thomas, O(N),
+/// em arrays fixos, sem alocação).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CubicSpline<const N: usize> {
+    xs: [f32; N],
+    ys: [f32; N],
+    // Segunda derivada em cada nó
+    m: [f32; N],
+}
+
+impl<const N: usize> CubicSpline<N> {
+    /// Constrói a spline pelos nós `(xs[i], ys[i])`.
+    ///
+    /// Exige N >= 2 e `xs` estritamente crescente (verificado em debug).
+    #[inline]
+    pub fn new(xs: [f32; N], ys: [f32; N], boundary: SplineBoundary) -> Self {
+        assert!(N >= 2, "CubicSpline: precisa de pelo menos 2 nós");
+        debug_assert!(
+            xs.windows(2).all(|w| w[0] < w[1]),
+            "CubicSpline: xs deve ser estritamente crescente"
+        );
+
+        // Linha i: sub[i] * m[i-1] + diag[i] * m[i] + sup[i] * m[i+1] = rhs[i]
+        let mut sub = [0.0; N];
+        let mut diag = [0.0; N];
+        let mut sup = [0.0; N];
+        let mut rhs = [0.0; N];
+
+        for i in 1..N - 1 {
+            let h0 = xs[i] - xs[i - 1];
+            let h1 = xs[i + 1] - xs[i];
+            sub[i] = h0;
+            diag[i] = 2.0 * (h0 + h1);
+            sup[i] = h1;
+            rhs[i] = 6.0 * ((ys[i + 1] - ys[i]) / h1 - (ys[i] - ys[i - 1]) / h0);
+        }
+
+        let h_first = xs[1] - xs[0];
+        let h_last = xs[N - 1] - xs[N - 2];
+        match boundary {
+            SplineBoundary::Natural => {
+                diag[0] = 1.0;
+                diag[N - 1] = 1.0;
+            }
+            SplineBoundary::Clamped(d0, dn) => {
+                diag[0] = 2.0 * h_first;
+                sup[0] = h_first;
+                rhs[0] = 6.0 * ((ys[1] - ys[0]) / h_first - d0);
+                sub[N - 1] = h_last;
+                diag[N - 1] = 2.0 * h_last;
+                rhs[N - 1] = 6.0 * (dn - (ys[N - 1] - ys[N - 2]) / h_last);
+            }
+        }
+
+        // Eliminação para frente (o sistema é diagonal dominante)
+        for i in 1..N {
+            let w = sub[i] / diag[i - 1];
+            diag[i] -= w * sup[i - 1];
+            rhs[i] -= w * rhs[i - 1];
+        }
+
+        // Substituição para trás
+        let mut m = [0.0; N];
+        m[N - 1] = rhs[N - 1] / diag[N - 1];
+        for i in (0..N - 1).rev() {
+            m[i] = (rhs[i] - sup[i] * m[i + 1]) / diag[i];
+        }
+
+        Self { xs, ys, m }
+    }
+
+    /// Avalia a spline em `t`.
+    ///
+    /// Fora de [xs[0], xs[N-1]] retorna o valor do nó da ponta; `t` NaN
+    /// retorna NaN.
+    #[inline]
+    pub fn sample(&self, t: f32) -> f32 {
+        // NaN falha as comparações com as pontas e quebraria a busca do nó
+        if isnanf(t) {
+            return f32::NAN;
+        }
+        if t <= self.xs[0] {
+            return self.ys[0];
+        }
+        if t >= self.xs[N - 1] {
+            return self.ys[N - 1];
+        }
+
+        // xs[i] <= t < xs[i + 1]
+        let i = self.xs.partition_point(|&k| k <= t) - 1;
+        let h = self.xs[i + 1] - self.xs[i];
+        let a = (self.xs[i + 1] - t) / h;
+        let b = 1.0 - a;
+
+        a * self.ys[i]
+            + b * self.ys[i + 1]
+            + ((a * a * a - a) * self.m[i] + (b * b * b - b) * self.m[i + 1]) * (h * h / 6.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Derivada por diferença central
+    fn slope(f: impl Fn(f32) -> f32, t: f32) -> f32 {
+        let h = 1e-3;
+        (f(t + h) - f(t - h)) / (2.0 * h)
+    }
+
+    #[test]
+    fn hermite_interpolates_values_and_tangents() {
+        let (p0, m0, p1, m1) = (2.0, -3.0, 5.0, 4.0);
+        let f = |t| hermitef(p0, m0, p1, m1, t);
+
+        assert_eq!(f(0.0), p0);
+        assert_eq!(f(1.0), p1);
+        assert!((slope(f, 0.0) - m0).abs() < 1e-2, "m0 ~ {}", slope(f, 0.0));
+        assert!((slope(f, 1.0) - m1).abs() < 1e-2, "m1 ~ {}", slope(f, 1.0));
+    }
+
+    #[test]
+    fn bezier_tangent_matches_finite_difference() {
+        let (p0, p1, p2, p3) = (0.0, 4.0, -2.0, 3.0);
+        let f = |t| cubic_bezierf(p0, p1, p2, p3, t);
+        for i in 1..10 {
+            let t = i as f32 / 10.0;
+            let analytic = cubic_bezier_tangentf(p0, p1, p2, p3, t);
+            assert!(
+                (analytic - slope(f, t)).abs() < 1e-2,
+                "t = {}: {} vs {}",
+                t,
+                analytic,
+                slope(f, t)
+            );
+        }
+        // Nas pontas a tangente é 3 × (p1 - p0) e 3 × (p3 - p2)
+        assert_eq!(cubic_bezier_tangentf(p0, p1, p2, p3, 0.0), 12.0);
+        assert_eq!(cubic_bezier_tangentf(p0, p1, p2, p3, 1.0), 15.0);
+    }
+
+    const TIMES: [f32; 3] = [0.0, 1.0, 3.0];
+    const VALUES: [f32; 3] = [10.0, 20.0, 0.0];
+
+    #[test]
+    fn sample_curve_modes_between_keyframes() {
+        // t = 1.5: um quarto do caminho entre 20 e 0
+        let linear = sample_curvef(&TIMES, &VALUES, 1.5, InterpMode::Linear);
+        let step = sample_curvef(&TIMES, &VALUES, 1.5, InterpMode::Step);
+        let smooth = sample_curvef(&TIMES, &VALUES, 1.5, InterpMode::Smooth);
+        assert!((linear - 15.0).abs() < 1e-5);
+        assert_eq!(step, 20.0);
+        // smoothstep(0.25) = 0.15625
+        assert!((smooth - (20.0 - 20.0 * 0.15625)).abs() < 1e-5);
+
+        assert_eq!(lerp_curvef(&TIMES, &VALUES, 0.5), 15.0);
+        assert_eq!(
+            sample_curvef(&TIMES, &VALUES, 0.999, InterpMode::Step),
+            10.0
+        );
+    }
+
+    #[test]
+    fn sample_curve_hits_keyframes_and_clamps() {
+        for mode in [InterpMode::Linear, InterpMode::Step, InterpMode::Smooth] {
+            for (t, v) in TIMES.iter().zip(VALUES.iter()) {
+                assert_eq!(sample_curvef(&TIMES, &VALUES, *t, mode), *v, "{:?}", mode);
+            }
+            assert_eq!(sample_curvef(&TIMES, &VALUES, -5.0, mode), 10.0);
+            assert_eq!(sample_curvef(&TIMES, &VALUES, 9.0, mode), 0.0);
+            assert_eq!(sample_curvef(&[], &[], 1.0, mode), 0.0);
+        }
+    }
+
+    const XS: [f32; 5] = [0.0, 1.0, 2.5, 3.0, 5.0];
+    const YS: [f32; 5] = [1.0, 3.0, -1.0, 0.5, 2.0];
+
+    #[test]
+    fn spline_passes_through_knots() {
+        for boundary in [SplineBoundary::Natural, SplineBoundary::Clamped(2.0, -1.0)] {
+            let s = CubicSpline::new(XS, YS, boundary);
+            for (x, y) in XS.iter().zip(YS.iter()) {
+                assert!((s.sample(*x) - y).abs() < 1e-5, "{:?}: x = {}", boundary, x);
+            }
+            assert_eq!(s.sample(-1.0), YS[0]);
+            assert_eq!(s.sample(9.0), YS[4]);
+        }
+    }
+
+    #[test]
+    fn spline_is_c1_at_interior_knots() {
+        let h = 1e-3;
+        for boundary in [SplineBoundary::Natural, SplineBoundary::Clamped(2.0, -1.0)] {
+            let s = CubicSpline::new(XS, YS, boundary);
+            for &x in &XS[1..4] {
+                // Valor dos dois lados do nó
+                assert!((s.sample(x - 1e-4) - s.sample(x + 1e-4)).abs() < 1e-2);
+                // Derivadas laterais coincidem
+                let left = (s.sample(x) - s.sample(x - h)) / h;
+                let right = (s.sample(x + h) - s.sample(x)) / h;
+                assert!(
+                    (left - right).abs() < 2e-2,
+                    "x = {}: {} vs {}",
+                    x,
+                    left,
+                    right
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn spline_boundary_conditions() {
+        let h = 1e-3;
+        let s = CubicSpline::new(XS, YS, SplineBoundary::Clamped(2.0, -1.0));
+        assert!(((s.sample(h) - s.sample(0.0)) / h - 2.0).abs() < 2e-2);
+        assert!(((s.sample(5.0) - s.sample(5.0 - h)) / h + 1.0).abs() < 2e-2);
+
+        // Spline natural de pontos colineares é a própria reta
+        let line = CubicSpline::new(
+            [0.0, 1.0, 3.0, 4.0],
+            [1.0, 3.0, 7.0, 9.0],
+            SplineBoundary::Natural,
+        );
+        for i in 0..=40 {
+            let t = i as f32 * 0.1;
+            assert!((line.sample(t) - (1.0 + 2.0 * t)).abs() < 1e-5, "t = {}", t);
+        }
+    }
+
+    #[test]
+    fn sample_curve_nan_time_returns_nan() {
+        for mode in [InterpMode::Linear, InterpMode::Step, InterpMode::Smooth] {
+            assert!(
+                sample_curvef(&TIMES, &VALUES, f32::NAN, mode).is_nan(),
+                "{:?}",
+                mode
+            );
+        }
+        assert!(lerp_curvef(&TIMES, &VALUES, f32::NAN).is_nan());
+        // Sem keyframes continua 0
+        assert_eq!(sample_curvef(&[], &[], f32::NAN, InterpMode::Linear), 0.0);
+    }
+
+    #[test]
+    fn spline_nan_time_returns_nan() {
+        for boundary in [SplineBoundary::Natural, SplineBoundary::Clamped(2.0, -1.0)] {
+            let s = CubicSpline::new(XS, YS, boundary);
+            assert!(s.sample(f32::NAN).is_nan(), "{:?}", boundary);
+        }
+    }
+}
@@ -0,0 +1,130 @@
+//! # Dithering
+//!
+//! Matrizes de Bayer para dithering ordenado, sem alocação.
+
+use crate::round::floorf;
+use crate::util::saturatef;
+
+// =============================================================================
+// MATRIZES DE BAYER
+// =============================================================================
+
+/// Matriz de Bayer 2x2 (valores 0..4).
+pub const BAYER_2X2: [[u8; 2]; 2] = [[0, 2], [3, 1]];
+
+/// Matriz de Bayer 4x4 (valores 0..16).
+pub const BAYER_4X4: [[u8; 4]; 4] = [[0, 8, 2, 10], [12, 4, 14, 6], [3, 11, 1, 9], [15, 7, 13, 5]];
+
+/// Matriz de Bayer 8x8 (valores 0..64).
+pub const BAYER_8X8: [[u8; 8]; 8] = [
+    [0, 32, 8, 40, 2, 34, 10, 42],
+    [48, 16, 56, 24, 50, 18, 58, 26],
+    [12, 44, 4, 36, 14, 46, 6, 38],
+    [60, 28, 52, 20, 62, 30, 54, 22],
+    [3, 35, 11, 43, 1, 33, 9, 41],
+    [51, 19, 59, 27, 49, 17, 57, 25],
+    [15, 47, 7, 39, 13, 45, 5, 37],
+    [63, 31, 55, 23, 61, 29, 53, 21],
+];
+
+/// Limiar de Bayer em [0, 1) para o pixel (x, y).
+///
+/// `size` escolhe a matriz: 2, 4 ou 8 (outros valores usam 8).
+/// A matriz se repete pela imagem.
+#[inline]
+pub const fn bayer_threshold(x: u32, y: u32, size: u32) -> f32 {
+    let (value, cells) = match size {
+        2 => (BAYER_2X2[(y % 2) as usize][(x % 2) as usize], 4.0),
+        4 => (BAYER_4X4[(y % 4) as usize][(x % 4) as usize], 16.0),
+        _ => (BAYER_8X8[(y % 8) as usize][(x % 8) as usize], 64.0),
+    };
+    value as f32 / cells
+}
+
+// =============================================================================
+// QUANTIZAÇÃO
+// =============================================================================
+
+/// Quantiza `value` em [0, 1] para `levels` níveis usando um limiar.
+///
+/// Com `threshold` vindo de [`bayer_threshold`], o erro de quantização
+/// vira um padrão ordenado. Retorna o nível quantizado em [0, 1];
+/// `levels < 2` retorna 0.
+#[inline]
+pub fn dither_quantizef(value: f32, threshold: f32, levels: u32) -> f32 {
+    if levels < 2 {
+        return 0.0;
+    }
+
+    let steps = (levels - 1) as f32;
+    let q = floorf(saturatef(value) * steps + threshold);
+    saturatef(q / steps)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Construção recursiva: M(2n) = [[4M, 4M+2], [4M+3, 4M+1]]
+    fn bayer_recursive<const N: usize>(prev: &[[u8; N]; N]) -> [[u8; 16]; 16] {
+        let mut out = [[0u8; 16]; 16];
+        for y in 0..N {
+            for x in 0..N {
+                let v = 4 * prev[y][x];
+                out[y][x] = v;
+                out[y][x + N] = v + 2;
+                out[y + N][x] = v + 3;
+                out[y + N][x + N] = v + 1;
+            }
+        }
+        out
+    }
+
+    #[test]
+    fn bayer_4x4_is_normalized_pattern() {
+        let expected = bayer_recursive(&BAYER_2X2);
+        let mut seen = [false; 16];
+        for y in 0..4 {
+            for x in 0..4 {
+                assert_eq!(BAYER_4X4[y][x], expected[y][x]);
+                let t = bayer_threshold(x as u32, y as u32, 4);
+                assert_eq!(t, BAYER_4X4[y][x] as f32 / 16.0);
+                assert!((0.0..1.0).contains(&t));
+                seen[BAYER_4X4[y][x] as usize] = true;
+            }
+        }
+        assert!(seen.iter().all(|&s| s));
+        assert_eq!(bayer_threshold(1, 0, 4), 0.5);
+        assert_eq!(bayer_threshold(3, 3, 4), 5.0 / 16.0);
+        // A matriz se repete
+        assert_eq!(bayer_threshold(5, 6, 4), bayer_threshold(1, 2, 4));
+    }
+
+    #[test]
+    fn bayer_8x8_follows_recursion() {
+        let expected = bayer_recursive(&BAYER_4X4);
+        for y in 0..8 {
+            for x in 0..8 {
+                assert_eq!(BAYER_8X8[y][x], expected[y][x]);
+            }
+        }
+    }
+
+    #[test]
+    fn dither_quantize_levels() {
+        // Média dos limiares de 4x4 reproduz o valor de entrada
+        let value = 0.3;
+        let mut sum = 0.0;
+        for y in 0..4 {
+            for x in 0..4 {
+                let q = dither_quantizef(value, bayer_threshold(x, y, 4), 2);
+                assert!(q == 0.0 || q == 1.0);
+                sum += q;
+            }
+        }
+        assert!((sum / 16.0 - value).abs() <= 1.0 / 16.0);
+        assert_eq!(dither_quantizef(1.0, 0.99, 4), 1.0);
+        assert_eq!(dither_quantizef(0.0, 0.0, 4), 0.0);
+        assert_eq!(dither_quantizef(0.5, 0.5, 1), 0.0);
+    }
+}
@@ -0,0 +1,191 @@
+//! # Easing
+//!
+//! Curvas de easing para animação. Todas recebem `t` em [0, 1] e
+//! retornam 0 em t=0 e 1 em t=1.
+
+use crate::consts::{PI, TAU};
+use crate::exp::exp2f;
+use crate::trig::{cosf, sinf};
+use crate::util::saturatef;
+
+// =============================================================================
+// QUADRÁTICAS
+// =============================================================================
+
+/// Ease-in quadrático: `t²`.
+#[inline]
+pub fn ease_in_quadf(t: f32) -> f32 {
+    let t = saturatef(t);
+    t * t
+}
+
+/// Ease-out quadrático: `1 - (1 - t)²`.
+#[inline]
+pub fn ease_out_quadf(t: f32) -> f32 {
+    let u = 1.0 - saturatef(t);
+    1.0 - u * u
+}
+
+/// Ease-in-out quadrático: acelera até t=0.5 e desacelera depois.
+#[inline]
+pub fn ease_in_out_quadf(t: f32) -> f32 {
+    let t = saturatef(t);
+    if t < 0.5 {
+        2.0 * t * t
+    } else {
+        let u = 1.0 - t;
+        1.0 - 2.0 * u * u
+    }
+}
+
+// =============================================================================
+// CÚBICAS
+// =============================================================================
+
+/// Ease-in cúbico: `t³`.
+#[inline]
+pub fn ease_in_cubicf(t: f32) -> f32 {
+    let t = saturatef(t);
+    t * t * t
+}
+
+/// Ease-out cúbico: `1 - (1 - t)³`.
+#[inline]
+pub fn ease_out_cubicf(t: f32) -> f32 {
+    let u = 1.0 - saturatef(t);
+    1.0 - u * u * u
+}
+
+/// Ease-in-out cúbico.
+#[inline]
+pub fn ease_in_out_cubicf(t: f32) -> f32 {
+    let t = saturatef(t);
+    if t < 0.5 {
+        4.0 * t * t * t
+    } else {
+        let u = 1.0 - t;
+        1.0 - 4.0 * u * u * u
+    }
+}
+
+// =============================================================================
+// SENOIDAL
+// =============================================================================
+
+/// Ease-in-out senoidal: `(1 - cos(πt)) / 2`.
+#[inline]
+pub fn ease_in_out_sinef(t: f32) -> f32 {
+    0.5 * (1.0 - cosf(PI * saturatef(t)))
+}
+
+// =============================================================================
+// OVERSHOOT
+// =============================================================================
+
+/// Overshoot padrão de `ease_out_backf` (~10% além do destino).
+pub const BACK_OVERSHOOT: f32 = 1.70158;
+
+/// Ease-out com recuo: passa de 1 e volta a se acomodar.
+///
+/// `1 + (s + 1)(t - 1)³ + s(t - 1)²`, com `s = overshoot`; use
+/// [`BACK_OVERSHOOT`] para o valor clássico. `overshoot = 0` vira
+/// um ease-out cúbico comum.
+#[inline]
+pub fn ease_out_backf(t: f32, overshoot: f32) -> f32 {
+    let u = t - 1.0;
+    let u2 = u * u;
+    1.0 + (overshoot + 1.0) * u2 * u + overshoot * u2
+}
+
+/// Ease-out elástico: oscila em torno de 1 com amplitude decaindo.
+///
+/// `2^(-10t) · sin((10t - 0.75) · 2π/3) + 1`.
+#[inline]
+pub fn ease_out_elasticf(t: f32) -> f32 {
+    // Pontas exatas: em t = 1 a fórmula dá 1 + 2^-11, não 1
+    if t <= 0.0 {
+        return 0.0;
+    }
+    if t >= 1.0 {
+        return 1.0;
+    }
+
+    exp2f(-10.0 * t) * sinf((10.0 * t - 0.75) * (TAU / 3.0)) + 1.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn back_and_elastic_hit_endpoints() {
+        for s in [0.0, 1.0, BACK_OVERSHOOT, 3.0] {
+            assert!(ease_out_backf(0.0, s).abs() < 1e-6, "s = {}", s);
+            assert_eq!(ease_out_backf(1.0, s), 1.0);
+        }
+        assert_eq!(ease_out_elasticf(0.0), 0.0);
+        assert_eq!(ease_out_elasticf(1.0), 1.0);
+    }
+
+    #[test]
+    fn ease_out_back_overshoots() {
+        let peak = (1..100)
+            .map(|i| ease_out_backf(i as f32 / 100.0, BACK_OVERSHOOT))
+            .fold(f32::MIN, f32::max);
+        // Pico clássico ~1.1
+        assert!(peak > 1.05 && peak < 1.15, "pico {}", peak);
+
+        // Sem overshoot, nunca passa de 1
+        for i in 0..=100 {
+            assert!(ease_out_backf(i as f32 / 100.0, 0.0) <= 1.0);
+        }
+    }
+
+    #[test]
+    fn ease_out_elastic_oscillates_and_settles() {
+        let values: [f32; 99] = core::array::from_fn(|i| ease_out_elasticf((i + 1) as f32 / 100.0));
+        assert!(values.iter().any(|&v| v > 1.0));
+        assert!(values.iter().any(|&v| v < 1.0));
+        // Amplitude decai: perto do fim fica colado em 1
+        assert!((values[98] - 1.0).abs() < 2e-3);
+    }
+
+    const CURVES: [fn(f32) -> f32; 7] = [
+        ease_in_quadf,
+        ease_out_quadf,
+        ease_in_out_quadf,
+        ease_in_cubicf,
+        ease_out_cubicf,
+        ease_in_out_cubicf,
+        ease_in_out_sinef,
+    ];
+
+    #[test]
+    fn curves_map_endpoints_and_clamp() {
+        for (i, f) in CURVES.iter().enumerate() {
+            assert!(f(0.0).abs() < 1e-6, "curva {}", i);
+            assert!((f(1.0) - 1.0).abs() < 1e-6, "curva {}", i);
+            // t fora de [0, 1] é saturado
+            assert_eq!(f(-2.0), f(0.0), "curva {}", i);
+            assert_eq!(f(3.0), f(1.0), "curva {}", i);
+        }
+    }
+
+    #[test]
+    fn in_out_curves_are_symmetric_around_half() {
+        for f in [ease_in_out_quadf, ease_in_out_cubicf, ease_in_out_sinef] {
+            assert!((f(0.5) - 0.5).abs() < 1e-6);
+            for i in 0..=20 {
+                let t = i as f32 / 20.0;
+                assert!((f(t) + f(1.0 - t) - 1.0).abs() < 1e-5, "t = {}", t);
+            }
+        }
+
+        // ease_out é o espelho de ease_in
+        for i in 0..=20 {
+            let t = i as f32 / 20.0;
+            assert!((ease_out_quadf(t) - (1.0 - ease_in_quadf(1.0 - t))).abs() < 1e-6);
+            assert!((ease_out_cubicf(t) - (1.0 - ease_in_cubicf(1.0 - t))).abs() < 1e-6);
+        }
+    }
+}
@@ -2,8 +2,9 @@
 //!
 //! Exponencial, logaritmo, potência e raiz quadrada.
 
-use crate::consts::LN_2;
-use crate::round::absf;
+use crate::approx::LOOSE_EPSILON_F32;
+use crate::consts::{LN_2, LN_2_F64, LOG10_E_F64, LOG2_E, LOG2_E_F64, SQRT_2, SQRT_2_F64};
+use crate::round::{abs, absf, copysignf, floorf, isinff, isnanf, round, roundf, trunc, truncf};
 
 // =============================================================================
 // SQRT
@@ -48,12 +49,57 @@ pub fn rsqrtf(x: f32) -> f32 {
     i = 0x5f3759df - (i >> 1);
     y = f32::from_bits(i);
 
-    // 1 iteração de Newton-Raphson
+    // 2 iterações de Newton-Raphson (erro relativo ~5e-6)
+    y = y * (1.5 - 0.5 * x * y * y);
     y = y * (1.5 - 0.5 * x * y * y);
 
     y
 }
 
+/// Recíproco aproximado (1/x) sem divisão.
+///
+/// Semente por negação do expoente nos bits (`0x7ef311c3 - bits`, erro
+/// < 5.1%) seguida de 2 iterações de Newton-Raphson `y = y(2 - xy)`,
+/// que elevam o erro ao quadrado: ~2.6e-3 após uma e < 1e-5 após duas,
+/// para |x| em [2^-126, 2^125). `x == 0` retorna `f32::MAX` com o sinal
+/// de x; fora dessa faixa (subnormais, |x| >= 2^125, infinitos e NaN) a
+/// semente daria a volta, então usa a divisão comum.
+#[inline]
+pub fn recipf(x: f32) -> f32 {
+    if x == 0.0 {
+        return copysignf(f32::MAX, x);
+    }
+    let biased_exp = (x.to_bits() >> 23) & 0xff;
+    if biased_exp == 0 || biased_exp >= RECIP_MAX_BIASED_EXP {
+        return 1.0 / x;
+    }
+
+    let y = recip_seed(x);
+    let y = recip_step(x, y);
+    recip_step(x, y)
+}
+
+// A constante centraliza o erro da semente em ±5%. A alternativa
+// 0x7eb53567, às vezes citada, erra até ~29% na semente e ainda ~7e-3
+// após duas iterações; nenhuma constante desta forma chega a 1e-3 com
+// uma só iteração, pois o erro da semente não cai abaixo de ~5%.
+const RECIP_MAGIC: u32 = 0x7ef311c3;
+
+// Expoente (com bias) a partir do qual RECIP_MAGIC - bits deixa de ser
+// um f32 normal: |x| >= 2^125
+const RECIP_MAX_BIASED_EXP: u32 = 252;
+
+#[inline]
+fn recip_seed(x: f32) -> f32 {
+    f32::from_bits(RECIP_MAGIC.wrapping_sub(x.to_bits()))
+}
+
+// Newton-Raphson para 1/x: o erro relativo e vira e²
+#[inline]
+fn recip_step(x: f32, y: f32) -> f32 {
+    y * (2.0 - x * y)
+}
+
 /// Raiz cúbica.
 #[inline]
 pub fn cbrtf(x: f32) -> f32 {
@@ -92,23 +138,61 @@ pub fn expf(x: f32) -> f32 {
         return 0.0;
     }
 
-    // Usa identidade: e^x = 2^(x/ln2)
-    // e^x = 2^k * e^r onde k = floor(x/ln2) e r = x - k*ln2
+    // e^x = 2^k * e^r, com k = round(x/ln2) e |r| <= ln2/2
+    let k = roundf(x * LOG2_E) as i32;
+    let kf = k as f32;
+
+    // Cody-Waite: ln2 em duas partes para r sair sem cancelamento
+    let r = (x - kf * LN_2_HI) - kf * LN_2_LO;
 
-    let k = (x / LN_2) as i32;
-    let r = x - (k as f32) * LN_2;
+    // e^r por Taylor de grau 6 (erro < 2e-7 em |r| <= ln2/2)
+    let mut exp_r = 1.0 / 720.0;
+    exp_r = exp_r * r + 1.0 / 120.0;
+    exp_r = exp_r * r + 1.0 / 24.0;
+    exp_r = exp_r * r + 1.0 / 6.0;
+    exp_r = exp_r * r + 0.5;
+    exp_r = exp_r * r + 1.0;
+    exp_r = exp_r * r + 1.0;
 
-    // Aproximação polinomial de e^r para r pequeno
-    // e^r ≈ 1 + r + r²/2 + r³/6 + r⁴/24 + r⁵/120
-    let r2 = r * r;
-    let r3 = r2 * r;
-    let r4 = r2 * r2;
-    let r5 = r4 * r;
+    // k é inteiro: 2^k vai direto para os bits do expoente. Perto de -88
+    // o resultado é subnormal (k = -127) e a escala é feita em dois passos
+    if k < -126 {
+        exp_r * pow2i_bits(k + 126) * pow2i_bits(-126)
+    } else {
+        exp_r * pow2i_bits(k)
+    }
+}
+
+// ln2 = LN_2_HI + LN_2_LO; LN_2_HI tem os 12 bits baixos da mantissa
+// zerados, então k * LN_2_HI é exato para |k| < 2^12
+const LN_2_HI: f32 = 0.693145751953125;
+const LN_2_LO: f32 = 1.428606765330187e-6;
+
+// 2^k montando o expoente IEEE-754 (k limitado a [-126, 127])
+#[inline]
+fn pow2i_bits(k: i32) -> f32 {
+    let k = k.clamp(-126, 127);
+    f32::from_bits(((k + 127) as u32) << 23)
+}
 
-    let exp_r = 1.0 + r + r2 / 2.0 + r3 / 6.0 + r4 / 24.0 + r5 / 120.0;
+/// Exponencial aproximada (e^x) de Schraudolph.
+///
+/// Monta o float diretamente: `bits = x * 2^23/ln2 + (127 * 2^23 - C)`.
+/// Erro relativo máximo de ~3%; use `expf` quando precisar de precisão.
+/// Faixa válida: [-87, 88]; fora dela satura em 0 ou `f32::MAX`.
+#[inline]
+pub fn expf_fast(x: f32) -> f32 {
+    if x > 88.0 {
+        return f32::MAX;
+    }
+    if x < -87.0 {
+        return 0.0;
+    }
 
-    // Multiplica por 2^k
-    exp_r * exp2f(k as f32)
+    // C = 366400 minimiza o erro relativo máximo
+    const A: f32 = 12102203.0; // 2^23 / ln(2)
+    const B: f32 = 1064986816.0; // 127 * 2^23 - 366400
+    f32::from_bits((A * x + B) as u32)
 }
 
 /// 2^x (mais eficiente que expf para bases 2).
@@ -137,6 +221,46 @@ pub fn exp2f(x: f32) -> f32 {
     pow2_k * pow2_f
 }
 
+/// 2^x aproximado (erro relativo < 0.3%).
+///
+/// Monta 2^floor(x) pelos bits do expoente e aproxima 2^f, f em [0, 1),
+/// por um polinômio quadrático. Faixa válida: [-126, 127].
+#[inline]
+pub fn exp2f_fast(x: f32) -> f32 {
+    if x >= 128.0 {
+        return f32::MAX;
+    }
+    if x < -126.0 {
+        return 0.0;
+    }
+
+    let k = floorf(x);
+    let f = x - k;
+
+    let pow2_k = f32::from_bits(((k as i32 + 127) as u32) << 23);
+    let pow2_f = 1.0 + f * (0.6602 + f * 0.3398);
+
+    pow2_k * pow2_f
+}
+
+/// log₂(x) aproximado (erro absoluto < 0.008).
+///
+/// Extrai o expoente pelos bits e aproxima log₂ da mantissa por um
+/// polinômio quadrático. Retorna `f32::MIN` para x <= 0; subnormais não
+/// são tratados.
+#[inline]
+pub fn log2f_fast(x: f32) -> f32 {
+    if x <= 0.0 {
+        return f32::MIN;
+    }
+
+    let bits = x.to_bits();
+    let e = ((bits >> 23) & 0xff) as i32 - 127;
+    let f = f32::from_bits((bits & 0x007fffff) | 0x3f800000) - 1.0;
+
+    e as f32 + f * (1.3466 - f * 0.3466)
+}
+
 /// Logaritmo natural (ln).
 #[inline]
 pub fn logf(x: f32) -> f32 {
@@ -146,17 +270,36 @@ pub fn logf(x: f32) -> f32 {
 
     // Decompõe x = m * 2^e onde 1 <= m < 2
     let bits = x.to_bits();
-    let e = ((bits >> 23) & 0xff) as i32 - 127;
-    let m = f32::from_bits((bits & 0x007fffff) | 0x3f800000);
+    let mut e = ((bits >> 23) & 0xff) as i32 - 127;
+    let mut m = f32::from_bits((bits & 0x007fffff) | 0x3f800000);
+
+    // Centraliza m em [√2/2, √2): perto de x = 1 (inclusive logo abaixo)
+    // ln(m) fica pequeno e não cancela contra e * ln(2)
+    if m > SQRT_2 {
+        m *= 0.5;
+        e += 1;
+    }
 
-    // ln(x) = ln(m * 2^e) = ln(m) + e * ln(2)
-    // Aproximação polinomial de ln(m) para m em [1, 2)
-    let m_minus_1 = m - 1.0;
-    let ln_m = m_minus_1 * (1.0 - m_minus_1 * (0.5 - m_minus_1 * (0.333333 - m_minus_1 * 0.25)));
+    // ln(m) = 2 atanh(s), s = (m - 1) / (m + 1), |s| <= 0.1716.
+    // Escrito como f - f²/2 + s(f²/2 + R(s²)), com R minimax (fdlibm)
+    let f = m - 1.0;
+    let s = f / (2.0 + f);
+    let z = s * s;
+    let w = z * z;
+    let r = z * (LOG_LG1 + w * LOG_LG3) + w * (LOG_LG2 + w * LOG_LG4);
+    let hfsq = 0.5 * f * f;
 
-    ln_m + (e as f32) * LN_2
+    // e * ln(2) em duas partes: e * LN_2_HI é exato
+    let ef = e as f32;
+    s * (hfsq + r) + ef * LN_2_LO - hfsq + f + ef * LN_2_HI
 }
 
+// Coeficientes minimax de R(s²) ≈ 2s²/3 + 2s⁴/5 + 2s⁶/7 + 2s⁸/9
+const LOG_LG1: f32 = 0.66666662693;
+const LOG_LG2: f32 = 0.40000972152;
+const LOG_LG3: f32 = 0.28498786688;
+const LOG_LG4: f32 = 0.24279078841;
+
 /// Logaritmo base 2.
 #[inline]
 pub fn log2f(x: f32) -> f32 {
@@ -169,11 +312,32 @@ pub fn log10f(x: f32) -> f32 {
     logf(x) / 2.302585
 }
 
+/// Logaritmo em base arbitrária: ln(x) / ln(base).
+///
+/// x <= 0 retorna `f32::MIN`, como `logf`. Bases sem logaritmo definido
+/// (base <= 0, base == 1 ou NaN) retornam NaN.
+#[inline]
+pub fn logf_base(x: f32, base: f32) -> f32 {
+    if isnanf(base) || base <= 0.0 || base == 1.0 {
+        return f32::NAN;
+    }
+    if x <= 0.0 {
+        return f32::MIN;
+    }
+
+    logf(x) / logf(base)
+}
+
 // =============================================================================
 // POW
 // =============================================================================
 
 /// Potência (x^y).
+///
+/// Expoentes inteiros usam `powi`, então bases negativas funcionam
+/// (`powf(-2.0, 3.0) == -8.0`). Para base negativa e expoente `±1/n` com
+/// n ímpar retorna a raiz real (`powf(-8.0, 1.0 / 3.0) == -2.0`); outros
+/// expoentes fracionários com base negativa retornam NaN.
 #[inline]
 pub fn powf(x: f32, y: f32) -> f32 {
     if x == 0.0 {
@@ -184,6 +348,10 @@ pub fn powf(x: f32, y: f32) -> f32 {
         return 1.0;
     }
 
+    if isnanf(x) || isnanf(y) {
+        return f32::NAN;
+    }
+
     if y == 1.0 {
         return x;
     }
@@ -192,35 +360,64 @@ pub fn powf(x: f32, y: f32) -> f32 {
         return x * x;
     }
 
-    if y == 0.5 {
-        return sqrtf(x);
+    // Expoente inteiro exato: exponenciação binária. Não usa tolerância,
+    // pois acima de 2^23 todo f32 é inteiro e a diferença seria sempre zero
+    if truncf(y) == y {
+        if absf(y) < 2147483648.0 {
+            return powi(x, y as i32);
+        }
+        // |y| >= 2^31 é par: o sinal da base some
+        return expf(y * logf(absf(x)));
+    }
+
+    if x < 0.0 {
+        // Raiz ímpar de base negativa: x^(1/n) = -(-x)^(1/n)
+        let inv = 1.0 / y;
+        let n = roundf(inv);
+        if absf(inv - n) < LOOSE_EPSILON_F32 && absf(n) < ODD_ROOT_LIMIT && (n as i32) & 1 != 0 {
+            return -expf(y * logf(-x));
+        }
+        return f32::NAN;
     }
 
-    // Para inteiros, use multiplicação
-    let y_int = y as i32;
-    if absf(y - y_int as f32) < 0.0001 {
-        return powi(x, y_int);
+    if y == 0.5 {
+        return sqrtf(x);
     }
 
     // Caso geral: x^y = e^(y * ln(x))
-    if x > 0.0 {
-        expf(y * logf(x))
-    } else {
-        // x negativo com expoente não-inteiro
-        0.0
+    expf(y * logf(x))
+}
+
+// Maior n aceito como raiz ímpar: além de 2^23 o recíproco em f32 não
+// distingue n de n ± 1
+const ODD_ROOT_LIMIT: f32 = 8388608.0;
+
+/// Potência aproximada para x em [0, 1] e y em (0, 1].
+///
+/// Calcula `exp2f_fast(y * log2f_fast(x))`, com erro relativo ~1%.
+/// Pensada para curvas de gama/resposta em loops críticos; fora desse
+/// domínio use `powf`.
+#[inline]
+pub fn powf_fast(x: f32, y: f32) -> f32 {
+    if x <= 0.0 {
+        return 0.0;
     }
+
+    exp2f_fast(y * log2f_fast(x))
 }
 
 /// Potência com expoente inteiro.
+///
+/// `const fn`: pode ser usada em constantes (`const K: f32 = powi(10.0, 6)`).
 #[inline]
-pub fn powi(x: f32, n: i32) -> f32 {
+pub const fn powi(x: f32, n: i32) -> f32 {
     if n == 0 {
         return 1.0;
     }
 
     let mut result = 1.0;
     let mut base = x;
-    let mut exp = if n < 0 { -n } else { n } as u32;
+    let mut exp = n.unsigned_abs();
 
     // Exponenciação binária
     while exp > 0 {
@@ -245,6 +442,14 @@ pub fn powi(x: f32, n: i32) -> f32 {
 /// Hipotenusa sqrt(x² + y²) sem overflow.
 #[inline]
 pub fn hypotf(x: f32, y: f32) -> f32 {
+    // Infinito vence até NaN (IEEE-754); inf/inf daria NaN abaixo
+    if isinff(x) || isinff(y) {
+        return f32::INFINITY;
+    }
+    if isnanf(x) || isnanf(y) {
+        return f32::NAN;
+    }
+
     let x = absf(x);
     let y = absf(y);
 
@@ -257,3 +462,823 @@ pub fn hypotf(x: f32, y: f32) -> f32 {
     let ratio = min / max;
     max * sqrtf(1.0 + ratio * ratio)
 }
+
+/// Hipotenusa aproximada sem raiz ("alpha max plus beta min").
+///
+/// `α·max(|x|, |y|) + β·min(|x|, |y|)` com α ≈ 0.9604 e β ≈ 0.3978,
+/// os coeficientes que minimizam o erro máximo: erro relativo de até
+/// ~4% (para mais e para menos). Use `hypotf` quando precisar de precisão.
+#[inline]
+pub fn hypotf_fast(x: f32, y: f32) -> f32 {
+    let x = absf(x);
+    let y = absf(y);
+
+    let (max, min) = if x > y { (x, y) } else { (y, x) };
+
+    HYPOT_FAST_ALPHA * max + HYPOT_FAST_BETA * min
+}
+
+const HYPOT_FAST_ALPHA: f32 = 0.960433870103;
+const HYPOT_FAST_BETA: f32 = 0.397824734759;
+
+/// Norma 3D sqrt(x² + y² + z²) sem overflow.
+#[inline]
+pub fn hypot3f(x: f32, y: f32, z: f32) -> f32 {
+    let x = absf(x);
+    let y = absf(y);
+    let z = absf(z);
+
+    let max = if x > y { x } else { y };
+    let max = if max > z { max } else { z };
+
+    if max == 0.0 {
+        return 0.0;
+    }
+
+    let (rx, ry, rz) = (x / max, y / max, z / max);
+    max * sqrtf(rx * rx + ry * ry + rz * rz)
+}
+
+// =============================================================================
+// HIPERBÓLICAS
+// =============================================================================
+
+/// Seno hiperbólico: (e^x - e^-x) / 2.
+#[inline]
+pub fn sinhf(x: f32) -> f32 {
+    // Perto de zero a subtração cancela; usa Taylor até x⁷
+    if absf(x) < 0.5 {
+        let x2 = x * x;
+        return x * (1.0 + x2 / 6.0 * (1.0 + x2 / 20.0 * (1.0 + x2 / 42.0)));
+    }
+
+    let e = expf(x);
+    0.5 * (e - 1.0 / e)
+}
+
+/// Cosseno hiperbólico: (e^x + e^-x) / 2.
+#[inline]
+pub fn coshf(x: f32) -> f32 {
+    let e = expf(absf(x));
+    0.5 * (e + 1.0 / e)
+}
+
+/// Tangente hiperbólica.
+///
+/// Para |x| > 9 retorna ±1 (tanh já é 1 em f32), evitando dividir dois
+/// `expf` enormes.
+#[inline]
+pub fn tanhf(x: f32) -> f32 {
+    if x > 9.0 {
+        return 1.0;
+    }
+    if x < -9.0 {
+        return -1.0;
+    }
+
+    sinhf(x) / coshf(x)
+}
+
+/// Tangente hiperbólica aproximada, sem `expf`.
+///
+/// Aproximante de Padé [5/4] de tanh em torno de zero,
+/// `x(945 + 105x² + x⁴) / (945 + 420x² + 15x⁴)`, limitado a ±1: erro
+/// absoluto máximo ~1.4e-3 (em |x| ≈ 3.65), monótona e exatamente ±1
+/// a partir daí. Pensada para ativações em inferência; use
+/// `tanhf` quando a precisão importa.
+#[inline]
+pub fn tanhf_fast(x: f32) -> f32 {
+    // Também evita x⁴ estourar para |x| enorme
+    if x > 9.0 {
+        return 1.0;
+    }
+    if x < -9.0 {
+        return -1.0;
+    }
+
+    let x2 = x * x;
+    let p = x * (945.0 + x2 * (105.0 + x2));
+    let q = 945.0 + x2 * (420.0 + 15.0 * x2);
+
+    // Acima de |x| ≈ 3.65 a racional passa de 1 e cresce como x / 15
+    (p / q).clamp(-1.0, 1.0)
+}
+
+/// Seno hiperbólico inverso: ln(x + sqrt(x² + 1)).
+#[inline]
+pub fn asinhf(x: f32) -> f32 {
+    let sign = if x < 0.0 { -1.0 } else { 1.0 };
+    let a = absf(x);
+
+    // Perto de zero, Taylor evita ln(1 + ε)
+    if a < 0.125 {
+        let x2 = x * x;
+        return x * (1.0 - x2 * (1.0 / 6.0 - x2 * (3.0 / 40.0 - x2 * (15.0 / 336.0))));
+    }
+
+    // x² estoura para |x| grande; lá asinh(x) ≈ ln(2|x|)
+    if a > 1e9 {
+        return sign * (logf(a) + LN_2);
+    }
+
+    sign * logf(a + sqrtf(a * a + 1.0))
+}
+
+/// Cosseno hiperbólico inverso: ln(x + sqrt(x² - 1)).
+///
+/// Fora do domínio (x < 1) retorna 0.0.
+#[inline]
+pub fn acoshf(x: f32) -> f32 {
+    if x < 1.0 {
+        return 0.0;
+    }
+
+    if x > 1e9 {
+        return logf(x) + LN_2;
+    }
+
+    logf(x + sqrtf(x * x - 1.0))
+}
+
+/// Tangente hiperbólica inversa: ln((1 + x) / (1 - x)) / 2.
+///
+/// Para |x| >= 1 satura em `f32::MAX` / `f32::MIN` conforme o sinal.
+#[inline]
+pub fn atanhf(x: f32) -> f32 {
+    if x >= 1.0 {
+        return f32::MAX;
+    }
+    if x <= -1.0 {
+        return f32::MIN;
+    }
+
+    if absf(x) < 0.125 {
+        let x2 = x * x;
+        return x * (1.0 + x2 * (1.0 / 3.0 + x2 * (1.0 / 5.0 + x2 * (1.0 / 7.0))));
+    }
+
+    0.5 * logf((1.0 + x) / (1.0 - x))
+}
+
+// =============================================================================
+// ERF
+// =============================================================================
+
+/// Função erro erf(x).
+///
+/// Aproximação de Abramowitz & Stegun 7.1.26 (erro absoluto ~1.5e-7,
+/// mais o erro de `expf`).
+#[inline]
+pub fn erff(x: f32) -> f32 {
+    let sign = if x < 0.0 { -1.0 } else { 1.0 };
+    let x = absf(x);
+
+    let t = 1.0 / (1.0 + 0.3275911 * x);
+    let poly = t
+        * (0.254829592
+            + t * (-0.284496736 + t * (1.421413741 + t * (-1.453152027 + t * 1.061405429))));
+
+    sign * (1.0 - poly * expf(-x * x))
+}
+
+// =============================================================================
+// ATIVAÇÃO
+// =============================================================================
+
+/// Sigmoide logística 1 / (1 + e^-x).
+///
+/// Estável para |x| grande: só calcula exponenciais de argumentos
+/// não positivos.
+#[inline]
+pub fn sigmoidf(x: f32) -> f32 {
+    if x >= 0.0 {
+        1.0 / (1.0 + expf(-x))
+    } else {
+        let e = expf(x);
+        e / (1.0 + e)
+    }
+}
+
+/// Derivada da sigmoide: s(x) * (1 - s(x)).
+///
+/// Máximo de 0.25 em x = 0.
+#[inline]
+pub fn sigmoid_derivf(x: f32) -> f32 {
+    sigmoid_deriv_from_output(sigmoidf(x))
+}
+
+/// Derivada da sigmoide a partir da ativação `s = sigmoidf(x)` já calculada.
+#[inline]
+pub fn sigmoid_deriv_from_output(s: f32) -> f32 {
+    s * (1.0 - s)
+}
+
+// =============================================================================
+// F64 VERSIONS
+// =============================================================================
+
+/// Exponencial (e^x) para f64.
+///
+/// Redução de Cody-Waite (x = k·ln2 + r, |r| <= ln2/2) e Taylor de
+/// grau 13 para e^r. Erro relativo ~1e-15.
+#[inline]
+pub fn exp(x: f64) -> f64 {
+    if x.is_nan() {
+        return x;
+    }
+    if x > 709.782712893384 {
+        return f64::MAX;
+    }
+    if x < -745.1332191019412 {
+        return 0.0;
+    }
+
+    // ln2 dividido em parte alta (exata com k pequeno) e parte baixa
+    const LN_2_HI: f64 = 6.93147180369123816490e-01;
+    const LN_2_LO: f64 = 1.90821492927058770002e-10;
+
+    let k = round(x * LOG2_E_F64);
+    let r = (x - k * LN_2_HI) - k * LN_2_LO;
+
+    // Taylor de e^r em Horner: 1 + r(1 + r/2(1 + r/3(1 + ...)))
+    let mut exp_r = 1.0;
+    let mut n = 13.0;
+    while n > 0.0 {
+        exp_r = 1.0 + exp_r * r / n;
+        n -= 1.0;
+    }
+
+    scale_pow2_f64(exp_r, k as i32)
+}
+
+/// Logaritmo natural (ln) para f64.
+///
+/// Reduz a mantissa para [√½, √2) e usa a série de atanh em
+/// s = (m-1)/(m+1). Retorna `f64::MIN` para x <= 0.
+#[inline]
+pub fn ln(x: f64) -> f64 {
+    if x.is_nan() {
+        return x;
+    }
+    if x <= 0.0 {
+        return f64::MIN;
+    }
+    if x == f64::INFINITY {
+        return x;
+    }
+
+    // Subnormais: normaliza multiplicando por 2^54
+    let (x, bias) = if x < f64::MIN_POSITIVE {
+        (x * 18014398509481984.0, -54)
+    } else {
+        (x, 0)
+    };
+
+    let bits = x.to_bits();
+    let mut e = ((bits >> 52) & 0x7ff) as i32 - 1023 + bias;
+    let mut m = f64::from_bits((bits & 0x000f_ffff_ffff_ffff) | 0x3ff0_0000_0000_0000);
+
+    if m > SQRT_2_F64 {
+        m *= 0.5;
+        e += 1;
+    }
+
+    // ln(m) = 2 * atanh(s) = 2(s + s³/3 + s⁵/5 + ...)
+    let s = (m - 1.0) / (m + 1.0);
+    let s2 = s * s;
+    let mut p = 1.0 / 23.0;
+    let mut k = 21.0;
+    while k >= 1.0 {
+        p = p * s2 + 1.0 / k;
+        k -= 2.0;
+    }
+    let ln_m = 2.0 * s * p;
+
+    ln_m + (e as f64) * LN_2_F64
+}
+
+/// Logaritmo base 2 para f64.
+#[inline]
+pub fn log2(x: f64) -> f64 {
+    if x <= 0.0 {
+        return f64::MIN;
+    }
+    ln(x) * LOG2_E_F64
+}
+
+/// Logaritmo base 10 para f64.
+#[inline]
+pub fn log10(x: f64) -> f64 {
+    if x <= 0.0 {
+        return f64::MIN;
+    }
+    ln(x) * LOG10_E_F64
+}
+
+/// Potência (x^y) para f64.
+///
+/// Mesmas convenções de `powf`: 0^y com y <= 0 retorna `f64::MAX`, base
+/// negativa com expoente `±1/n` (n ímpar) retorna a raiz real
+/// (`pow(-8.0, 1.0 / 3.0) == -2.0`) e com outros expoentes fracionários
+/// retorna NaN.
+#[inline]
+pub fn pow(x: f64, y: f64) -> f64 {
+    if x == 0.0 {
+        return if y > 0.0 { 0.0 } else { f64::MAX };
+    }
+
+    if y == 0.0 {
+        return 1.0;
+    }
+
+    if x.is_nan() || y.is_nan() {
+        return f64::NAN;
+    }
+
+    if y == 1.0 {
+        return x;
+    }
+
+    // Expoente inteiro: exponenciação binária
+    if trunc(y) == y {
+        if abs(y) < 2147483648.0 {
+            return powi_f64(x, y as i32);
+        }
+        // |y| >= 2^31: sinal da base pela paridade de y (todo |y| >= 2^53
+        // é par)
+        let r = exp(y * ln(abs(x)));
+        let odd = abs(y) < 9007199254740992.0 && (y as i64) & 1 != 0;
+        return if x < 0.0 && odd { -r } else { r };
+    }
+
+    if x < 0.0 {
+        // Raiz ímpar de base negativa: x^(1/n) = -(-x)^(1/n)
+        let inv = 1.0 / y;
+        let n = round(inv);
+        if abs(inv - n) <= abs(n) * ODD_ROOT_TOLERANCE_F64
+            && abs(n) < ODD_ROOT_LIMIT_F64
+            && (n as i64) & 1 != 0
+        {
+            return -exp(y * ln(-x));
+        }
+        return f64::NAN;
+    }
+
+    exp(y * ln(x))
+}
+
+// Tolerância relativa para reconhecer 1/y como inteiro: 1.0 / 3.0 não é
+// exatamente um terço, e o recíproco erra por alguns ulps
+const ODD_ROOT_TOLERANCE_F64: f64 = 1e-12;
+
+// Maior n aceito como raiz ímpar: além de 2^52 o recíproco em f64 não
+// distingue n de n ± 1
+const ODD_ROOT_LIMIT_F64: f64 = 4503599627370496.0;
+
+/// Raiz cúbica para f64.
+#[inline]
+pub fn cbrt(x: f64) -> f64 {
+    if x == 0.0 || x.is_nan() || x.is_infinite() {
+        return x;
+    }
+
+    let sign = if x < 0.0 { -1.0 } else { 1.0 };
+    let x = abs(x);
+
+    // Estimativa inicial pelo expoente: cbrt(m * 2^e) ≈ 2^(e/3)
+    let e = ((x.to_bits() >> 52) & 0x7ff) as i32 - 1023;
+    let mut y = scale_pow2_f64(1.0, e / 3);
+
+    // Newton-Raphson: y = (2y + x/y²) / 3
+    for _ in 0..8 {
+        y = (2.0 * y + x / (y * y)) / 3.0;
+    }
+
+    sign * y
+}
+
+// y * 2^k montando o expoente nos bits, em até dois passos
+fn scale_pow2_f64(y: f64, k: i32) -> f64 {
+    let mut y = y;
+    let mut k = k;
+
+    while k > 1023 {
+        y *= f64::from_bits(((1023 + 1023) as u64) << 52);
+        k -= 1023;
+    }
+    while k < -1022 {
+        y *= f64::from_bits(1u64 << 52); // 2^-1022
+        k += 1022;
+    }
+
+    y * f64::from_bits(((k + 1023) as u64) << 52)
+}
+
+// Potência inteira para f64 (exponenciação binária)
+fn powi_f64(x: f64, n: i32) -> f64 {
+    let mut result = 1.0;
+    let mut base = x;
+    let mut exp = n.unsigned_abs();
+
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result *= base;
+        }
+        base *= base;
+        exp >>= 1;
+    }
+
+    if n < 0 {
+        1.0 / result
+    } else {
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sigmoid_derivative_peaks_at_zero_and_is_symmetric() {
+        assert_eq!(sigmoid_derivf(0.0), 0.25);
+        assert_eq!(sigmoid_deriv_from_output(0.5), 0.25);
+
+        let mut prev = 0.25;
+        for i in 1..=40 {
+            let x = i as f32 * 0.25;
+            let d = sigmoid_derivf(x);
+            assert!(d < prev, "não decresce em x = {x}");
+            assert!(
+                (d - sigmoid_derivf(-x)).abs() < 1e-6,
+                "assimétrica em x = {x}"
+            );
+            prev = d;
+        }
+    }
+
+    #[test]
+    fn expf_fast_relative_error_is_bounded() {
+        // Varredura da faixa documentada [-87, 88] em passos de 1e-3
+        let mut worst = 0.0f64;
+        for i in 0..=175_000 {
+            let x = (-87.0 + i as f32 * 1e-3).min(88.0);
+            let exact = (x as f64).exp();
+            worst = worst.max(((expf_fast(x) as f64 - exact) / exact).abs());
+        }
+        assert!(worst < 0.031, "erro relativo máximo {worst}");
+
+        assert_eq!(expf_fast(100.0), f32::MAX);
+        assert_eq!(expf_fast(-100.0), 0.0);
+    }
+
+    #[test]
+    fn powi_is_const() {
+        const P: f32 = powi(2.0, 10);
+        const K: f32 = powi(10.0, 6);
+        const INV: f32 = powi(2.0, -3);
+        assert_eq!(P, 1024.0);
+        assert_eq!(K, 1.0e6);
+        assert_eq!(INV, 0.125);
+        assert_eq!(powi(-3.0, 3), -27.0);
+        assert_eq!(powi(0.0, 0), 1.0);
+    }
+
+    #[test]
+    fn powf_fast_relative_error_is_bounded() {
+        // Grade sobre (0, 1] × (0, 1], com x também em escala logarítmica
+        let mut worst = 0.0f64;
+        for i in 1..=400 {
+            let y = i as f32 / 400.0;
+            for j in 1..=1000 {
+                for x in [j as f32 / 1000.0, powi(0.5, j / 10)] {
+                    let reference = (x as f64).powf(y as f64);
+                    let err = ((powf_fast(x, y) as f64 - reference) / reference).abs();
+                    worst = worst.max(err);
+                }
+            }
+        }
+        assert!(worst < 0.01, "erro relativo máximo {}", worst);
+        assert_eq!(powf_fast(0.0, 0.5), 0.0);
+    }
+
+    fn rel_err(got: f64, expected: f64) -> f64 {
+        ((got - expected) / expected).abs()
+    }
+
+    #[test]
+    fn f64_exp_log_match_reference() {
+        for x in [
+            -700.0,
+            -20.5,
+            -1.0,
+            -1e-8,
+            0.5,
+            1.0,
+            2.302585092994046,
+            10.0,
+            700.0,
+        ] {
+            assert!(rel_err(exp(x), x.exp()) < 1e-12, "exp({})", x);
+        }
+        for x in [1e-300, 1e-10, 0.1, 0.5, 0.999, 2.0, 10.0, 12345.678, 1e300] {
+            assert!(rel_err(ln(x), x.ln()) < 1e-12, "ln({})", x);
+            assert!(rel_err(log2(x), x.log2()) < 1e-12, "log2({})", x);
+            assert!(rel_err(log10(x), x.log10()) < 1e-12, "log10({})", x);
+        }
+        assert_eq!(exp(0.0), 1.0);
+        assert_eq!(ln(1.0), 0.0);
+        assert!((exp(1.0) - core::f64::consts::E).abs() < 1e-15);
+        assert!((log10(1000.0) - 3.0).abs() < 1e-14);
+        assert!((log2(1024.0) - 10.0).abs() < 1e-14);
+    }
+
+    #[test]
+    fn f64_pow_cbrt_match_reference() {
+        for (x, y) in [
+            (2.0, 0.5),
+            (10.0, -3.7),
+            (0.3, 12.25),
+            (1.0001, 1e4),
+            (7.0, 3.0),
+        ] {
+            assert!(
+                rel_err(pow(x, y), f64::powf(x, y)) < 1e-12,
+                "pow({}, {})",
+                x,
+                y
+            );
+        }
+        assert_eq!(pow(2.0, 10.0), 1024.0);
+        for x in [-1000.0, -2.0, 1e-30, 0.125, 3.0, 27.0, 1e30] {
+            assert!(rel_err(cbrt(x), x.cbrt()) < 1e-12, "cbrt({})", x);
+        }
+        assert_eq!(cbrt(-8.0), -2.0);
+        assert_eq!(cbrt(0.0), 0.0);
+    }
+
+    #[test]
+    fn hyperbolic_known_values() {
+        assert_eq!(sinhf(0.0), 0.0);
+        assert_eq!(coshf(0.0), 1.0);
+        assert_eq!(tanhf(0.0), 0.0);
+        for x in [1.0f32, -1.0, 0.3, 2.5, -4.0, 8.0, -12.0] {
+            let xd = x as f64;
+            let tol = |v: f64| 1e-4 * v.abs().max(1.0);
+            assert!(
+                ((sinhf(x) as f64) - xd.sinh()).abs() < tol(xd.sinh()),
+                "sinh({})",
+                x
+            );
+            assert!(
+                ((coshf(x) as f64) - xd.cosh()).abs() < tol(xd.cosh()),
+                "cosh({})",
+                x
+            );
+            assert!(((tanhf(x) as f64) - xd.tanh()).abs() < 1e-4, "tanh({})", x);
+        }
+        assert!((sinhf(1.0) - 1.175_201_2).abs() < 1e-4);
+        assert!((coshf(-1.0) - 1.543_080_6).abs() < 1e-4);
+        assert!((tanhf(1.0) - 0.761_594_2).abs() < 1e-4);
+    }
+
+    #[test]
+    fn tanh_saturates_without_nan() {
+        for x in [9.5f32, 50.0, 100.0, 1e10, f32::MAX, f32::INFINITY] {
+            assert_eq!(tanhf(x), 1.0);
+            assert_eq!(tanhf(-x), -1.0);
+        }
+        // Logo abaixo do corte continua finito e ≤ 1
+        let t = tanhf(8.99);
+        assert!(t.is_finite() && t <= 1.0 && t > 0.9999);
+    }
+
+    #[test]
+    fn inverse_hyperbolic_round_trips() {
+        for x in [-5.0f32, -1.0, -0.3, -0.01, 0.0, 0.05, 0.5, 2.0, 7.5] {
+            let r = sinhf(asinhf(x));
+            assert!(
+                (r - x).abs() < 1e-4 * x.abs().max(1.0),
+                "sinh(asinh({})) = {}",
+                x,
+                r
+            );
+        }
+        for x in [1.0f32, 1.001, 1.5, 3.0, 20.0] {
+            let r = coshf(acoshf(x));
+            assert!((r - x).abs() < 1e-4 * x, "cosh(acosh({})) = {}", x, r);
+        }
+        for x in [-0.99f32, -0.5, -0.1, 0.0, 0.01, 0.3, 0.9] {
+            let r = tanhf(atanhf(x));
+            assert!((r - x).abs() < 1e-4, "tanh(atanh({})) = {}", x, r);
+        }
+        // Argumentos grandes sem overflow em x²
+        assert!((asinhf(1e20) - (1e20f64.asinh() as f32)).abs() < 1e-3);
+        assert!((acoshf(1e20) - (1e20f64.acosh() as f32)).abs() < 1e-3);
+    }
+
+    #[test]
+    fn inverse_hyperbolic_domain_guards() {
+        assert_eq!(acoshf(0.5), 0.0);
+        assert_eq!(acoshf(-3.0), 0.0);
+        assert_eq!(atanhf(1.0), f32::MAX);
+        assert_eq!(atanhf(2.0), f32::MAX);
+        assert_eq!(atanhf(-1.0), f32::MIN);
+        assert_eq!(atanhf(-2.0), f32::MIN);
+    }
+
+    #[test]
+    fn expf_matches_reference_values() {
+        for x in [0.0f32, 1.0, 10.0, -10.0, 50.0, -50.0, 0.5, -87.0, 88.0] {
+            let reference = (x as f64).exp();
+            let err = ((expf(x) as f64 - reference) / reference).abs();
+            assert!(err < 1e-4, "expf({}) erro relativo {}", x, err);
+        }
+        assert_eq!(expf(0.0), 1.0);
+        assert!((expf(1.0) - core::f32::consts::E).abs() < 1e-6);
+    }
+
+    #[test]
+    fn expf_is_accurate_across_range() {
+        let mut worst = 0.0f64;
+        for i in 0..=17_500 {
+            let x = -87.0 + i as f32 * 0.01;
+            let reference = (x as f64).exp();
+            worst = worst.max(((expf(x) as f64 - reference) / reference).abs());
+        }
+        assert!(worst < 1e-5, "erro relativo máximo {}", worst);
+    }
+
+    #[test]
+    fn hypotf_fast_error_is_bounded() {
+        let mut worst = 0.0f32;
+        for i in 0..10_000 {
+            let (s, c) = crate::trig::sincosf(i as f32 * (crate::consts::TAU / 10_000.0));
+            for r in [1e-3, 1.0, 37.5, 1e6] {
+                let (x, y) = (r * c, r * s);
+                let exact = hypotf(x, y);
+                worst = worst.max((hypotf_fast(x, y) - exact).abs() / exact);
+            }
+        }
+        assert!(worst < 0.04, "erro relativo máximo {}", worst);
+        assert_eq!(hypotf_fast(0.0, 0.0), 0.0);
+    }
+
+    // Pior erro relativo de 1/x sobre mantissas de várias magnitudes
+    fn recip_worst(f: impl Fn(f32) -> f32) -> f64 {
+        let mut worst = 0.0f64;
+        for e in [-126, -60, -20, -1, 0, 1, 7, 30, 90, 124] {
+            for m in (0..1u32 << 23).step_by(211) {
+                let bits = (((e + 127) as u32) << 23) | m;
+                for x in [f32::from_bits(bits), -f32::from_bits(bits)] {
+                    let err = ((f(x) as f64) * (x as f64) - 1.0).abs();
+                    worst = worst.max(err);
+                }
+            }
+        }
+        worst
+    }
+
+    #[test]
+    fn recipf_error_after_one_and_two_iterations() {
+        let seed = recip_worst(recip_seed);
+        let one = recip_worst(|x| recip_step(x, recip_seed(x)));
+        let two = recip_worst(recipf);
+        assert!(seed < 0.052, "semente: {}", seed);
+        assert!(one < 2.7e-3, "uma iteração: {}", one);
+        assert!(two < 1e-5, "duas iterações: {}", two);
+    }
+
+    #[test]
+    fn recipf_special_values() {
+        assert_eq!(recipf(0.0), f32::MAX);
+        assert_eq!(recipf(-0.0), f32::MIN);
+        assert_eq!(recipf(f32::INFINITY), 0.0);
+        assert!(recipf(f32::NAN).is_nan());
+        assert!((recipf(4.0) * 4.0 - 1.0).abs() < 1e-5);
+        assert!((recipf(-3.0) * -3.0 - 1.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn f64_pow_negative_base() {
+        // Expoente inteiro par e ímpar
+        assert_eq!(pow(-2.0, 2.0), 4.0);
+        assert_eq!(pow(-2.0, 3.0), -8.0);
+        assert_eq!(pow(-3.0, -2.0), 1.0 / 9.0);
+        assert_eq!(pow(-0.5, -3.0), -8.0);
+
+        // Raiz ímpar real, como em powf
+        assert!(rel_err(pow(-8.0, 1.0 / 3.0), -2.0) < 1e-15);
+        assert!(rel_err(pow(-32.0, -1.0 / 5.0), -0.5) < 1e-15);
+        assert!(rel_err(pow(-27.0, 1.0 / 3.0), cbrt(-27.0)) < 1e-14);
+        assert_eq!(powf(-8.0, 1.0 / 3.0), -2.0);
+
+        // Raiz par ou expoente fracionário qualquer: NaN
+        assert!(pow(-8.0, 0.5).is_nan());
+        assert!(pow(-8.0, 0.25).is_nan());
+        assert!(pow(-2.0, 1.7).is_nan());
+    }
+
+    #[test]
+    fn tanhf_fast_error_is_bounded() {
+        // Varredura densa em [-5, 5]: erro absoluto documentado ~1.4e-3
+        let mut max_err = 0.0f64;
+        for i in 0..=100_000 {
+            let x = -5.0 + 10.0 * i as f32 / 100_000.0;
+            let err = (tanhf_fast(x) as f64 - (x as f64).tanh()).abs();
+            max_err = max_err.max(err);
+        }
+        assert!(max_err < 1.5e-3, "erro máximo {}", max_err);
+
+        // Ímpar, limitada e saturada fora da faixa
+        assert_eq!(tanhf_fast(0.0), 0.0);
+        assert_eq!(tanhf_fast(-2.0), -tanhf_fast(2.0));
+        for x in [4.0f32, 9.5, 1e10, f32::INFINITY] {
+            assert_eq!(tanhf_fast(x), 1.0);
+            assert_eq!(tanhf_fast(-x), -1.0);
+        }
+    }
+
+    #[test]
+    fn logf_relative_error_sweep() {
+        // 0.5 a 100 em passos geométricos
+        let mut x = 0.5f32;
+        while x <= 100.0 {
+            if x != 1.0 {
+                let reference = (x as f64).ln();
+                assert!(rel_err(logf(x) as f64, reference) < 1e-5, "logf({})", x);
+            }
+            x *= 1.0007;
+        }
+
+        // Vizinhança de 1, dos dois lados: ln(x) pequeno não pode cancelar
+        assert_eq!(logf(1.0), 0.0);
+        let one = 1.0f32.to_bits();
+        for k in 1..2000u32 {
+            for x in [f32::from_bits(one + k * 37), f32::from_bits(one - k * 19)] {
+                let reference = (x as f64).ln();
+                assert!(rel_err(logf(x) as f64, reference) < 1e-5, "logf({:e})", x);
+            }
+        }
+    }
+
+    #[test]
+    fn logf_base_values_and_guards() {
+        assert!((logf_base(8.0, 2.0) - 3.0).abs() < 1e-4);
+        assert!((logf_base(1000.0, 10.0) - 3.0).abs() < 1e-4);
+        assert!((logf_base(0.25, 2.0) + 2.0).abs() < 1e-4);
+        assert!((logf_base(81.0, 3.0) - 4.0).abs() < 1e-4);
+        // Base entre 0 e 1 inverte o sinal
+        assert!((logf_base(8.0, 0.5) + 3.0).abs() < 1e-4);
+        assert_eq!(logf_base(1.0, 7.0), 0.0);
+
+        assert_eq!(logf_base(0.0, 2.0), f32::MIN);
+        assert_eq!(logf_base(-5.0, 10.0), f32::MIN);
+        for base in [1.0, 0.0, -2.0, f32::NAN] {
+            assert!(logf_base(8.0, base).is_nan(), "base {}", base);
+        }
+    }
+
+    #[test]
+    fn f64_pow_negative_base_huge_integer_exponent() {
+        assert_eq!(pow(-1.0, 2147483648.0), 1.0);
+        assert_eq!(pow(-1.0, 1099511627776.0), 1.0);
+        assert_eq!(pow(-1.0, 3e9 + 1.0), -1.0);
+        assert_eq!(pow(-1.0, -(3e9 + 1.0)), -1.0);
+        // Acima de 2^53 todo inteiro é par
+        assert_eq!(pow(-1.0, 1e17), 1.0);
+        assert!(pow(-2.0, 1e10) > 0.0);
+        assert_eq!(pow(-2.0, -1e10), 0.0);
+        // Mesmo resultado de powf
+        assert_eq!(powf(-1.0, 2147483648.0), 1.0);
+    }
+
+    #[test]
+    fn recipf_extreme_magnitudes() {
+        for x in [f32::MAX, 1e38, 4.3e37, 2.2e37, 1e-38, f32::MIN_POSITIVE] {
+            for x in [x, -x] {
+                let r = recipf(x);
+                let reference = 1.0 / x as f64;
+                assert!(
+                    r.is_finite() && (r > 0.0) == (x > 0.0),
+                    "recipf({:e}) = {:e}",
+                    x,
+                    r
+                );
+                assert!(
+                    ((r as f64 - reference) / reference).abs() < 1e-5,
+                    "recipf({:e}) = {:e}",
+                    x,
+                    r
+                );
+            }
+        }
+        // 1/MAX é subnormal: conferido contra a divisão exata
+        assert_eq!(recipf(f32::MAX), 1.0 / f32::MAX);
+        // Subnormais: o recíproco estoura para infinito com o sinal certo
+        assert_eq!(recipf(1e-40), f32::INFINITY);
+        assert_eq!(recipf(-1e-40), f32::NEG_INFINITY);
+        let tiny = f32::from_bits(0x0040_0000);
+        assert_eq!(recipf(tiny), 1.0 / tiny);
+    }
+}
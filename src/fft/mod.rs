@@ -0,0 +1,159 @@
+//! # FFT
+//!
+//! Transformada rápida de Fourier radix-2 (Cooley-Tukey), in-place e
+//! sem alocação. O comprimento precisa ser potência de dois.
+
+use crate::complex::Complex32;
+use crate::consts::TAU;
+use crate::trig::sincosf;
+
+// =============================================================================
+// BIT REVERSAL
+// =============================================================================
+
+/// Inverte a ordem dos `bits` bits menos significativos de `x`.
+///
+/// `bit_reverse(0b0011, 4) == 0b1100`. Bits acima de `bits` são
+/// descartados; `bits == 0` retorna 0.
+#[inline]
+pub const fn bit_reverse(x: u32, bits: u32) -> u32 {
+    if bits == 0 {
+        return 0;
+    }
+    x.reverse_bits() >> (32 - bits)
+}
+
+/// Reordena a slice pela permutação de bit reversal dos índices.
+///
+/// Primeiro passo da FFT iterativa. O comprimento precisa ser potência
+/// de dois (entra em pânico caso contrário).
+#[inline]
+pub fn bit_reverse_permute<T>(data: &mut [T]) {
+    let n = data.len();
+    assert!(
+        n.is_power_of_two() || n == 0,
+        "bit_reverse_permute: comprimento deve ser potência de dois"
+    );
+    if n <= 2 {
+        return;
+    }
+
+    let bits = n.trailing_zeros();
+    for i in 0..n {
+        let j = bit_reverse(i as u32, bits) as usize;
+        // Cada par é trocado uma vez só
+        if i < j {
+            data.swap(i, j);
+        }
+    }
+}
+
+// =============================================================================
+// FFT / IFFT
+// =============================================================================
+
+/// FFT in-place: `X[k] = Σ x[n]·e^(-2πikn/N)`.
+///
+/// Sem normalização (a inversa divide por N). O comprimento precisa ser
+/// potência de dois (entra em pânico caso contrário).
+#[inline]
+pub fn fft(data: &mut [Complex32]) {
+    transform(data, -1.0);
+}
+
+/// FFT inversa in-place: `x[n] = (1/N)·Σ X[k]·e^(2πikn/N)`.
+///
+/// `ifft(fft(x)) == x` a menos de arredondamento.
+#[inline]
+pub fn ifft(data: &mut [Complex32]) {
+    transform(data, 1.0);
+
+    let n = data.len();
+    if n > 0 {
+        let inv_n = 1.0 / n as f32;
+        for z in data.iter_mut() {
+            *z = z.scale(inv_n);
+        }
+    }
+}
+
+// Cooley-Tukey iterativo; `sign` é o sinal do expoente dos twiddles
+fn transform(data: &mut [Complex32], sign: f32) {
+    let n = data.len();
+    assert!(
+        n.is_power_of_two() || n == 0,
+        "fft: comprimento deve ser potência de dois"
+    );
+
+    bit_reverse_permute(data);
+
+    let mut len = 2;
+    while len <= n {
+        let half = len / 2;
+        let step = sign * TAU / len as f32;
+
+        for k in 0..half {
+            // Twiddle calculado direto (sem recorrência) para não acumular erro
+            let (s, c) = sincosf(step * k as f32);
+            let w = Complex32::new(c, s);
+
+            let mut i = k;
+            while i < n {
+                let a = data[i];
+                let b = data[i + half] * w;
+                data[i] = a + b;
+                data[i + half] = a - b;
+                i += len;
+            }
+        }
+
+        len *= 2;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::trig::cosf;
+
+    #[test]
+    fn fft_sinusoid_peaks_at_expected_bin() {
+        const N: usize = 64;
+        const BIN: usize = 5;
+        let mut data = [Complex32::new(0.0, 0.0); N];
+        for (i, z) in data.iter_mut().enumerate() {
+            *z = Complex32::new(cosf(TAU * (BIN * i) as f32 / N as f32), 0.0);
+        }
+        fft(&mut data);
+
+        // Cosseno real: metade da energia em k e metade em N - k
+        for (k, z) in data.iter().enumerate() {
+            if k == BIN || k == N - BIN {
+                assert!(
+                    (z.abs() - N as f32 / 2.0).abs() < 1e-3,
+                    "bin {}: {}",
+                    k,
+                    z.abs()
+                );
+            } else {
+                assert!(z.abs() < 1e-3, "bin {}: {}", k, z.abs());
+            }
+        }
+    }
+
+    #[test]
+    fn fft_ifft_round_trip() {
+        const N: usize = 32;
+        let mut original = [Complex32::new(0.0, 0.0); N];
+        for (i, z) in original.iter_mut().enumerate() {
+            let t = i as f32;
+            *z = Complex32::new(0.3 * t - 2.0, cosf(t * 0.7) * 1.5);
+        }
+        let mut data = original;
+        fft(&mut data);
+        ifft(&mut data);
+        for (a, b) in data.iter().zip(original.iter()) {
+            assert!((a.re - b.re).abs() < 1e-4 && (a.im - b.im).abs() < 1e-4);
+        }
+    }
+}
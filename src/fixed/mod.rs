@@ -0,0 +1,412 @@
+//! # Ponto Fixo
+//!
+//! Aritmética inteira em ponto fixo para caminhos sem FPU e para
+//! simulação determinística (mesmo resultado em qualquer máquina).
+
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+// =============================================================================
+// Q15
+// =============================================================================
+
+/// Fator de escala Q15 (1.0 = 32768; o maior ganho representável é 32767).
+pub const Q15_ONE: i32 = 1 << 15;
+
+/// Multiplica uma amostra por um ganho Q15 e acumula, com saturação.
+///
+/// Calcula `acc + round(sample * gain_q15 / 32768)`: o acumulador fica na
+/// mesma escala das amostras. O produto sempre cabe em i32 (no máximo
+/// 2^30); só a soma satura em `i32::MIN`/`i32::MAX`. Com ganho
+/// `i16::MAX` (≈ 0.99997) a amostra passa inalterada para |sample| < 16384
+/// e perde no máximo 1 LSB acima disso.
+#[inline]
+pub const fn mac_i16(acc: i32, sample: i16, gain_q15: i16) -> i32 {
+    let prod = sample as i32 * gain_q15 as i32;
+    // Arredonda para o mais próximo antes de voltar à escala da amostra
+    acc.saturating_add((prod + (1 << 14)) >> 15)
+}
+
+// =============================================================================
+// Q16.16
+// =============================================================================
+
+/// Número em ponto fixo Q16.16: 16 bits inteiros (com sinal) e 16
+/// fracionários, guardado em um i32.
+///
+/// Todas as operações são inteiras e determinísticas. Soma, subtração e
+/// negação dão a volta (wrap) no overflow, em debug e em release.
+/// Multiplicação e divisão arredondam para o mais próximo e saturam.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Fix16(pub i32);
+
+impl Fix16 {
+    /// Bits fracionários.
+    pub const FRAC_BITS: u32 = 16;
+    /// Zero.
+    pub const ZERO: Fix16 = Fix16(0);
+    /// Um (65536).
+    pub const ONE: Fix16 = Fix16(1 << 16);
+    /// Maior valor (~32767.99998).
+    pub const MAX: Fix16 = Fix16(i32::MAX);
+    /// Menor valor (-32768).
+    pub const MIN: Fix16 = Fix16(i32::MIN);
+
+    /// Converte um inteiro, saturando fora de [-32768, 32767].
+    #[inline]
+    pub const fn from_int(n: i32) -> Fix16 {
+        Fix16(saturate_i64((n as i64) << 16))
+    }
+
+    /// Converte de f32, arredondando para o múltiplo de 2^-16 mais próximo.
+    ///
+    /// Satura fora da faixa; NaN vira zero.
+    #[inline]
+    pub fn from_f32(x: f32) -> Fix16 {
+        let v = x * 65536.0;
+        // `as` satura e leva NaN a 0
+        Fix16(if v < 0.0 { v - 0.5 } else { v + 0.5 } as i32)
+    }
+
+    /// Converte para f32 (exato para |x| < 256; acima disso arredonda).
+    #[inline]
+    pub fn to_f32(self) -> f32 {
+        self.0 as f32 * (1.0 / 65536.0)
+    }
+
+    /// Parte inteira, arredondando para baixo.
+    #[inline]
+    pub const fn to_int(self) -> i32 {
+        self.0 >> 16
+    }
+
+    /// Raiz quadrada por Newton inteiro.
+    ///
+    /// Resultado truncado para o Q16.16 abaixo da raiz exata; negativos
+    /// retornam zero.
+    #[inline]
+    pub const fn sqrt(self) -> Fix16 {
+        if self.0 <= 0 {
+            return Fix16::ZERO;
+        }
+
+        // sqrt(a / 2^16) * 2^16 = sqrt(a * 2^16)
+        Fix16(isqrt_u64((self.0 as u64) << 16) as i32)
+    }
+}
+
+impl Add for Fix16 {
+    type Output = Fix16;
+
+    #[inline]
+    fn add(self, rhs: Fix16) -> Fix16 {
+        Fix16(self.0.wrapping_add(rhs.0))
+    }
+}
+
+impl Sub for Fix16 {
+    type Output = Fix16;
+
+    #[inline]
+    fn sub(self, rhs: Fix16) -> Fix16 {
+        Fix16(self.0.wrapping_sub(rhs.0))
+    }
+}
+
+impl Neg for Fix16 {
+    type Output = Fix16;
+
+    #[inline]
+    fn neg(self) -> Fix16 {
+        Fix16(self.0.wrapping_neg())
+    }
+}
+
+impl Mul for Fix16 {
+    type Output = Fix16;
+
+    #[inline]
+    fn mul(self, rhs: Fix16) -> Fix16 {
+        // Produto Q32.32 em i64; soma meio LSB antes de voltar a Q16.16
+        let p = self.0 as i64 * rhs.0 as i64;
+        Fix16(saturate_i64((p + (1 << 15)) >> 16))
+    }
+}
+
+/// Divisão com arredondamento; divisor zero satura para `MAX`/`MIN`
+/// conforme o sinal do dividendo (0 / 0 dá zero) em vez de entrar em pânico.
+impl Div for Fix16 {
+    type Output = Fix16;
+
+    #[inline]
+    fn div(self, rhs: Fix16) -> Fix16 {
+        if rhs.0 == 0 {
+            return match self.0 {
+                0 => Fix16::ZERO,
+                a if a > 0 => Fix16::MAX,
+                _ => Fix16::MIN,
+            };
+        }
+
+        // Arredonda a magnitude (empates para longe do zero)
+        let n = (self.0 as i64) << 16;
+        let d = rhs.0 as i64;
+        let q = (n.abs() + d.abs() / 2) / d.abs();
+        let q = if (n < 0) != (d < 0) { -q } else { q };
+        Fix16(saturate_i64(q))
+    }
+}
+
+#[inline]
+const fn saturate_i64(v: i64) -> i32 {
+    if v > i32::MAX as i64 {
+        i32::MAX
+    } else if v < i32::MIN as i64 {
+        i32::MIN
+    } else {
+        v as i32
+    }
+}
+
+// Raiz quadrada inteira (piso) por Newton, partindo de uma potência de
+// dois acima da raiz para convergir de cima
+#[inline]
+const fn isqrt_u64(n: u64) -> u64 {
+    if n < 2 {
+        return n;
+    }
+
+    let bits = u64::BITS - n.leading_zeros();
+    let mut x = 1u64 << bits.div_ceil(2);
+    loop {
+        let y = (x + n / x) / 2;
+        if y >= x {
+            return x;
+        }
+        x = y;
+    }
+}
+
+// =============================================================================
+// CORDIC
+// =============================================================================
+
+/// π em Q16.16.
+pub const FIX16_PI: Fix16 = Fix16(205887);
+/// π/2 em Q16.16.
+pub const FIX16_FRAC_PI_2: Fix16 = Fix16(102944);
+
+// atan(2^-i) em Q16.16, i = 0..16
+const CORDIC_ATAN: [i32; 17] = [
+    51472, 30386, 16055, 8150, 4091, 2047, 1024, 512, 256, 128, 64, 32, 16, 8, 4, 2, 1,
+];
+
+/// Arco tangente de dois argumentos em ponto fixo, por CORDIC.
+///
+/// Retorna radianos em Q16.16, em [-π, π], com as mesmas convenções de
+/// quadrante e eixos de `atan2f` (`(0, 0)` dá 0). Só shifts, somas e uma
+/// tabela de 17 ângulos: nenhuma operação de float. Erro ~5e-5 rad,
+/// limitado pela resolução de Q16.16 e pelas 17 iterações.
+#[inline]
+pub const fn atan2_fix16(y: Fix16, x: Fix16) -> Fix16 {
+    let mut x = x.0 as i64;
+    let mut y = y.0 as i64;
+    if x == 0 && y == 0 {
+        return Fix16::ZERO;
+    }
+
+    // Escala para ~2^30 para que os shifts não percam os bits de
+    // entradas pequenas (o ângulo não depende da escala)
+    let max = if x.abs() > y.abs() { x.abs() } else { y.abs() };
+    let shift = max.leading_zeros() as i32 - 33;
+    if shift > 0 {
+        x <<= shift;
+        y <<= shift;
+    }
+
+    // Semiplano esquerdo: gira ±90° para x >= 0 (CORDIC converge em ±99°)
+    let mut z: i64 = 0;
+    if x < 0 {
+        let t = x;
+        if y >= 0 {
+            x = y;
+            y = -t;
+            z = FIX16_FRAC_PI_2.0 as i64;
+        } else {
+            x = -y;
+            y = t;
+            z = -(FIX16_FRAC_PI_2.0 as i64);
+        }
+    }
+
+    // Modo vetorização: gira (x, y) até y = 0 somando os ângulos usados
+    let mut i = 0;
+    while i < CORDIC_ATAN.len() {
+        let (dx, dy) = (y >> i, x >> i);
+        if y > 0 {
+            x += dx;
+            y -= dy;
+            z += CORDIC_ATAN[i] as i64;
+        } else {
+            x -= dx;
+            y += dy;
+            z -= CORDIC_ATAN[i] as i64;
+        }
+        i += 1;
+    }
+
+    Fix16(z as i32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::consts::{PI, TAU};
+    use crate::trig::{atan2f, sincosf};
+
+    #[test]
+    fn mac_accumulates_scaled_samples() {
+        let half = (Q15_ONE / 2) as i16;
+        assert_eq!(mac_i16(0, 1000, half), 500);
+        assert_eq!(mac_i16(100, -1000, half), -400);
+        // Meio LSB arredonda para o mais próximo
+        assert_eq!(mac_i16(0, 3, half), 2);
+
+        let mut acc = 0;
+        for s in [1000i16, 2000, -500, 300] {
+            acc = mac_i16(acc, s, half);
+        }
+        assert_eq!(acc, 1400);
+    }
+
+    #[test]
+    fn mac_unity_gain_passes_sample() {
+        for s in [0i16, 1, -1, 1234, -16383, 16383] {
+            assert_eq!(mac_i16(0, s, i16::MAX), s as i32, "amostra {}", s);
+        }
+        // Acima de 16384 perde no máximo 1 LSB
+        for s in [i16::MAX, i16::MIN, 20000, -30000] {
+            assert!(
+                (mac_i16(0, s, i16::MAX) - s as i32).abs() <= 1,
+                "amostra {}",
+                s
+            );
+        }
+        // Ganho -1.0 exato inverte
+        assert_eq!(mac_i16(0, 1234, i16::MIN), -1234);
+    }
+
+    #[test]
+    fn mac_saturates_at_i32_bounds() {
+        assert_eq!(mac_i16(i32::MAX - 10, i16::MAX, i16::MAX), i32::MAX);
+        assert_eq!(mac_i16(i32::MIN + 10, i16::MIN, i16::MAX), i32::MIN);
+        assert_eq!(mac_i16(i32::MAX, -100, i16::MAX), i32::MAX - 100);
+        assert_eq!(mac_i16(i32::MIN, i16::MIN, i16::MIN), i32::MIN + 32768);
+    }
+
+    // Distância em LSBs de Q16.16 até o valor exato
+    fn ulps(got: Fix16, exact: f64) -> f64 {
+        (got.0 as f64 - exact * 65536.0).abs()
+    }
+
+    #[test]
+    fn fix16_integer_round_trips_are_exact() {
+        for n in [-32768, -1000, -1, 0, 1, 7, 12345, 32767] {
+            let f = Fix16::from_int(n);
+            assert_eq!(f.to_int(), n);
+            assert_eq!(f.to_f32(), n as f32);
+            assert_eq!(Fix16::from_f32(n as f32), f);
+        }
+        // Fora da faixa satura
+        assert_eq!(Fix16::from_int(40000), Fix16::MAX);
+        assert_eq!(Fix16::from_int(-40000), Fix16::MIN);
+        assert_eq!(Fix16::from_f32(f32::NAN), Fix16::ZERO);
+    }
+
+    #[test]
+    fn fix16_add_sub_are_exact() {
+        let sum = Fix16::from_f32(0.5) + Fix16::from_f32(0.25);
+        assert_eq!(sum, Fix16::from_f32(0.75));
+        assert_eq!(sum.to_f32(), 0.75);
+        assert_eq!(
+            Fix16::from_f32(0.75) - Fix16::from_f32(1.0),
+            Fix16::from_f32(-0.25)
+        );
+        assert_eq!(-Fix16::ONE, Fix16::from_int(-1));
+    }
+
+    #[test]
+    fn fix16_mul_div_within_one_ulp() {
+        let values = [0.5f32, -1.25, 3.14159, 100.0, -0.001, 7.75, 181.0];
+        for &a in &values {
+            for &b in &values {
+                let (fa, fb) = (Fix16::from_f32(a), Fix16::from_f32(b));
+                let (xa, xb) = (fa.to_f32() as f64, fb.to_f32() as f64);
+                assert!(ulps(fa * fb, xa * xb) <= 1.0, "{} * {}", a, b);
+                if xa.abs() / xb.abs() < 32767.0 {
+                    assert!(ulps(fa / fb, xa / xb) <= 1.0, "{} / {}", a, b);
+                }
+            }
+        }
+        assert_eq!(
+            Fix16::from_int(3) * Fix16::from_f32(0.5),
+            Fix16::from_f32(1.5)
+        );
+        // Saturação e divisão por zero
+        assert_eq!(Fix16::from_int(300) * Fix16::from_int(300), Fix16::MAX);
+        assert_eq!(Fix16::ONE / Fix16::ZERO, Fix16::MAX);
+        assert_eq!(-Fix16::ONE / Fix16::ZERO, Fix16::MIN);
+        assert_eq!(Fix16::ZERO / Fix16::ZERO, Fix16::ZERO);
+    }
+
+    #[test]
+    fn fix16_sqrt_truncates_exact_root() {
+        assert_eq!(Fix16::from_int(4).sqrt(), Fix16::from_int(2));
+        assert_eq!(Fix16::from_f32(0.25).sqrt(), Fix16::from_f32(0.5));
+        assert_eq!(Fix16::from_int(-4).sqrt(), Fix16::ZERO);
+        for x in [2.0f32, 0.1, 10.0, 1000.5, 32767.0] {
+            let f = Fix16::from_f32(x);
+            let exact = (f.0 as f64 / 65536.0).sqrt() * 65536.0;
+            // Piso da raiz exata
+            assert_eq!(f.sqrt().0, exact.floor() as i32, "sqrt({})", x);
+        }
+    }
+
+    #[test]
+    fn atan2_fix16_matches_atan2f_in_all_quadrants() {
+        let mut max_err = 0.0f32;
+        for i in 0..720 {
+            let a = -PI + i as f32 * (TAU / 720.0) + 0.001;
+            let (s, c) = sincosf(a);
+            for r in [0.01f32, 1.0, 300.0, 30000.0] {
+                let (y, x) = (Fix16::from_f32(s * r), Fix16::from_f32(c * r));
+                let got = atan2_fix16(y, x).to_f32();
+                let mut d = (got - atan2f(y.to_f32(), x.to_f32())).abs();
+                if d > PI {
+                    d = TAU - d;
+                }
+                max_err = max_err.max(d);
+            }
+        }
+        assert!(max_err < 1e-4, "erro máximo {}", max_err);
+    }
+
+    #[test]
+    fn atan2_fix16_axes() {
+        let (one, zero) = (Fix16::ONE, Fix16::ZERO);
+        let cases = [
+            (zero, one, 0.0),
+            (one, zero, FIX16_FRAC_PI_2.to_f32()),
+            (zero, -one, FIX16_PI.to_f32()),
+            (-one, zero, -FIX16_FRAC_PI_2.to_f32()),
+            (one, one, FIX16_PI.to_f32() / 4.0),
+            (-one, -one, -0.75 * FIX16_PI.to_f32()),
+        ];
+        for (y, x, expected) in cases {
+            let got = atan2_fix16(y, x).to_f32();
+            assert!((got - expected).abs() < 1e-4, "({:?}, {:?}): {}", y, x, got);
+        }
+        assert_eq!(atan2_fix16(zero, zero), zero);
+        // Menor valor representável ainda dá a direção certa
+        assert!((atan2_fix16(Fix16(1), Fix16(1)).to_f32() - 0.785398).abs() < 1e-4);
+    }
+}
@@ -0,0 +1,396 @@
+//! # Geometria
+//!
+//! Interseções e consultas geométricas.
+
+use crate::util::{clampf, maxf, minf, solve_quadraticf};
+use crate::vec::Vec3;
+
+// =============================================================================
+// INTERSEÇÃO DE RAIOS
+// =============================================================================
+
+/// Interseção raio-esfera.
+///
+/// Retorna a menor distância positiva ao longo do raio (em unidades de
+/// `dir`; se `dir` for unitário, é a distância euclidiana). Se a origem
+/// estiver dentro da esfera, retorna o ponto de saída.
+#[inline]
+pub fn ray_sphere_intersectf(origin: Vec3, dir: Vec3, center: Vec3, radius: f32) -> Option<f32> {
+    let oc = origin - center;
+
+    // |o + t·d - c|² = r²  =>  (d·d)t² + 2(oc·d)t + (oc·oc - r²) = 0
+    let a = dir.length_squared();
+    let b = 2.0 * oc.dot(dir);
+    let c = oc.length_squared() - radius * radius;
+
+    let (t0, t1) = solve_quadraticf(a, b, c)?;
+
+    if t0 > 0.0 {
+        Some(t0)
+    } else if t1 > 0.0 {
+        Some(t1)
+    } else {
+        None
+    }
+}
+
+/// Interseção raio-AABB (método dos slabs).
+///
+/// `min`/`max` são os cantos da caixa. Retorna a menor distância não
+/// negativa ao longo do raio; se a origem estiver dentro, retorna o
+/// ponto de saída.
+#[inline]
+pub fn ray_aabb_intersectf(origin: Vec3, dir: Vec3, min: Vec3, max: Vec3) -> Option<f32> {
+    let (mut t_near, mut t_far) = slab(origin.x, dir.x, min.x, max.x);
+
+    let (n, f) = slab(origin.y, dir.y, min.y, max.y);
+    t_near = maxf(t_near, n);
+    t_far = minf(t_far, f);
+
+    let (n, f) = slab(origin.z, dir.z, min.z, max.z);
+    t_near = maxf(t_near, n);
+    t_far = minf(t_far, f);
+
+    if t_near > t_far || t_far < 0.0 {
+        None
+    } else if t_near >= 0.0 {
+        Some(t_near)
+    } else {
+        Some(t_far)
+    }
+}
+
+// Intervalo [t_entrada, t_saída] de um slab em um eixo
+fn slab(o: f32, d: f32, min: f32, max: f32) -> (f32, f32) {
+    // Raio paralelo ao slab: dentro ou fora para todo t
+    if d == 0.0 {
+        return if o >= min && o <= max {
+            (f32::NEG_INFINITY, f32::INFINITY)
+        } else {
+            (f32::INFINITY, f32::NEG_INFINITY)
+        };
+    }
+
+    let inv = 1.0 / d;
+    let t0 = (min - o) * inv;
+    let t1 = (max - o) * inv;
+    if t0 < t1 {
+        (t0, t1)
+    } else {
+        (t1, t0)
+    }
+}
+
+// =============================================================================
+// PLANOS E SEGMENTOS
+// =============================================================================
+
+/// Distância com sinal de um ponto ao plano `normal·x + d = 0`.
+///
+/// Positiva do lado para onde a normal aponta. Assume `plane_normal`
+/// normalizada; caso contrário o resultado sai escalado por seu
+/// comprimento.
+#[inline]
+pub fn point_plane_distancef(point: Vec3, plane_normal: Vec3, plane_d: f32) -> f32 {
+    plane_normal.dot(point) + plane_d
+}
+
+/// Ponto mais próximo de `p` no segmento [a, b].
+///
+/// A projeção é limitada a [0, 1], então pontos além das pontas
+/// retornam a própria ponta. Segmento degenerado retorna `a`.
+#[inline]
+pub fn closest_point_on_segmentf(p: Vec3, a: Vec3, b: Vec3) -> Vec3 {
+    let ab = b - a;
+    let len_sq = ab.length_squared();
+    if len_sq == 0.0 {
+        return a;
+    }
+
+    let t = clampf((p - a).dot(ab) / len_sq, 0.0, 1.0);
+    a + ab.scale(t)
+}
+
+/// Pontos mais próximos entre os segmentos [p1, q1] e [p2, q2].
+///
+/// Retorna `(c1, c2)`, com `c1` no primeiro segmento e `c2` no segundo.
+/// Segmentos paralelos retornam um dos pares de mesma distância.
+#[inline]
+pub fn line_line_closest_pointsf(p1: Vec3, q1: Vec3, p2: Vec3, q2: Vec3) -> (Vec3, Vec3) {
+    let d1 = q1 - p1;
+    let d2 = q2 - p2;
+    let r = p1 - p2;
+
+    let a = d1.length_squared();
+    let e = d2.length_squared();
+    let f = d2.dot(r);
+
+    // Ambos degenerados em pontos
+    if a == 0.0 && e == 0.0 {
+        return (p1, p2);
+    }
+
+    let (s, t) = if a == 0.0 {
+        // Primeiro segmento é um ponto
+        (0.0, clampf(f / e, 0.0, 1.0))
+    } else {
+        let c = d1.dot(r);
+        if e == 0.0 {
+            // Segundo segmento é um ponto
+            (clampf(-c / a, 0.0, 1.0), 0.0)
+        } else {
+            let b = d1.dot(d2);
+            let denom = a * e - b * b;
+
+            // Paralelos (denom == 0): fixa s = 0
+            let mut s = if denom != 0.0 {
+                clampf((b * f - c * e) / denom, 0.0, 1.0)
+            } else {
+                0.0
+            };
+
+            // Ponto em L2 mais próximo de L1(s); se sair do segmento,
+            // limita t e recalcula s
+            let mut t = (b * s + f) / e;
+            if t < 0.0 {
+                t = 0.0;
+                s = clampf(-c / a, 0.0, 1.0);
+            } else if t > 1.0 {
+                t = 1.0;
+                s = clampf((b - c) / a, 0.0, 1.0);
+            }
+
+            (s, t)
+        }
+    };
+
+    (p1 + d1.scale(s), p2 + d2.scale(t))
+}
+
+// =============================================================================
+// RASTERIZAÇÃO
+// =============================================================================
+
+/// Função de aresta: (p - a) × (b - a) em 2D.
+///
+/// Em coordenadas de tela (y para baixo), é positiva quando `p` está à
+/// esquerda da aresta a→b, negativa à direita e zero sobre a reta.
+/// O módulo é o dobro da área do triângulo (a, b, p); dividindo as três
+/// funções de aresta de um triângulo pela sua área dupla obtêm-se as
+/// coordenadas baricêntricas.
+#[inline]
+pub fn edge_functionf(ax: f32, ay: f32, bx: f32, by: f32, px: f32, py: f32) -> f32 {
+    (px - ax) * (by - ay) - (py - ay) * (bx - ax)
+}
+
+// =============================================================================
+// POLÍGONOS
+// =============================================================================
+
+/// Área com sinal de um polígono simples (fórmula do laço).
+///
+/// Positiva para vértices em sentido anti-horário (eixo y para cima),
+/// negativa no sentido horário. Menos de 3 pontos retorna 0.
+#[inline]
+pub fn polygon_areaf(points: &[(f32, f32)]) -> f32 {
+    if points.len() < 3 {
+        return 0.0;
+    }
+
+    let mut sum = 0.0;
+    let mut prev = points[points.len() - 1];
+    for &p in points {
+        sum += prev.0 * p.1 - p.0 * prev.1;
+        prev = p;
+    }
+
+    0.5 * sum
+}
+
+/// Centroide (centro de massa) de um polígono simples.
+///
+/// Com menos de 3 pontos retorna o primeiro ponto (ou a origem se
+/// vazio); polígono degenerado (área zero) retorna a média dos vértices.
+#[inline]
+pub fn polygon_centroidf(points: &[(f32, f32)]) -> (f32, f32) {
+    if points.len() < 3 {
+        return if points.is_empty() {
+            (0.0, 0.0)
+        } else {
+            points[0]
+        };
+    }
+
+    let mut area2 = 0.0;
+    let mut cx = 0.0;
+    let mut cy = 0.0;
+    let mut prev = points[points.len() - 1];
+    for &p in points {
+        let cross = prev.0 * p.1 - p.0 * prev.1;
+        area2 += cross;
+        cx += (prev.0 + p.0) * cross;
+        cy += (prev.1 + p.1) * cross;
+        prev = p;
+    }
+
+    if area2 == 0.0 {
+        let mut sx = 0.0;
+        let mut sy = 0.0;
+        for &p in points {
+            sx += p.0;
+            sy += p.1;
+        }
+        let n = points.len() as f32;
+        return (sx / n, sy / n);
+    }
+
+    // C = Σ(pᵢ + pᵢ₊₁)·crossᵢ / (6A), com 2A = area2
+    let k = 1.0 / (3.0 * area2);
+    (cx * k, cy * k)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn close(a: f32, b: f32) -> bool {
+        (a - b).abs() < 1e-5
+    }
+
+    #[test]
+    fn ray_sphere_hit_miss_and_inside() {
+        let center = Vec3::new(0.0, 0.0, 10.0);
+        let forward = Vec3::new(0.0, 0.0, 1.0);
+
+        // Acerto direto: entra na face mais próxima
+        let t = ray_sphere_intersectf(Vec3::ZERO, forward, center, 2.0).unwrap();
+        assert!(close(t, 8.0), "t = {t}");
+
+        // Erro: passa ao lado
+        let miss = ray_sphere_intersectf(Vec3::new(3.0, 0.0, 0.0), forward, center, 2.0);
+        assert_eq!(miss, None);
+
+        // Esfera atrás do raio
+        let behind = ray_sphere_intersectf(Vec3::ZERO, -forward, center, 2.0);
+        assert_eq!(behind, None);
+
+        // Origem dentro: retorna a saída
+        let t = ray_sphere_intersectf(center, forward, center, 2.0).unwrap();
+        assert!(close(t, 2.0), "t = {t}");
+    }
+
+    #[test]
+    fn ray_aabb_hit_miss_and_inside() {
+        let min = Vec3::new(-1.0, -1.0, 4.0);
+        let max = Vec3::new(1.0, 1.0, 6.0);
+        let forward = Vec3::new(0.0, 0.0, 1.0);
+
+        let t = ray_aabb_intersectf(Vec3::ZERO, forward, min, max).unwrap();
+        assert!(close(t, 4.0), "t = {t}");
+
+        let miss = ray_aabb_intersectf(Vec3::new(2.0, 0.0, 0.0), forward, min, max);
+        assert_eq!(miss, None);
+
+        let t = ray_aabb_intersectf(Vec3::new(0.0, 0.0, 5.0), forward, min, max).unwrap();
+        assert!(close(t, 1.0), "t = {t}");
+    }
+
+    #[test]
+    fn plane_distance_sign_flips() {
+        let n = Vec3::new(0.0, 1.0, 0.0);
+        // Plano y = 2
+        assert!(close(
+            point_plane_distancef(Vec3::new(5.0, 3.0, 1.0), n, -2.0),
+            1.0
+        ));
+        assert!(close(
+            point_plane_distancef(Vec3::new(5.0, 0.5, 1.0), n, -2.0),
+            -1.5
+        ));
+        assert_eq!(
+            point_plane_distancef(Vec3::new(0.0, 2.0, 0.0), n, -2.0),
+            0.0
+        );
+    }
+
+    #[test]
+    fn closest_point_on_segment_clamps_to_endpoints() {
+        let a = Vec3::new(0.0, 0.0, 0.0);
+        let b = Vec3::new(4.0, 0.0, 0.0);
+
+        assert_eq!(
+            closest_point_on_segmentf(Vec3::new(2.0, 3.0, 0.0), a, b),
+            Vec3::new(2.0, 0.0, 0.0)
+        );
+        assert_eq!(
+            closest_point_on_segmentf(Vec3::new(-3.0, 1.0, 0.0), a, b),
+            a
+        );
+        assert_eq!(
+            closest_point_on_segmentf(Vec3::new(9.0, -1.0, 2.0), a, b),
+            b
+        );
+        // Segmento degenerado
+        assert_eq!(closest_point_on_segmentf(Vec3::new(1.0, 1.0, 1.0), a, a), a);
+    }
+
+    #[test]
+    fn segment_segment_closest_points() {
+        // Segmentos cruzados em alturas diferentes
+        let (c1, c2) = line_line_closest_pointsf(
+            Vec3::new(-1.0, 0.0, 0.0),
+            Vec3::new(1.0, 0.0, 0.0),
+            Vec3::new(0.0, -1.0, 2.0),
+            Vec3::new(0.0, 1.0, 2.0),
+        );
+        assert_eq!(c1, Vec3::new(0.0, 0.0, 0.0));
+        assert_eq!(c2, Vec3::new(0.0, 0.0, 2.0));
+
+        // Projeção fora do segundo segmento: limita na ponta
+        let (c1, c2) = line_line_closest_pointsf(
+            Vec3::new(-1.0, 0.0, 0.0),
+            Vec3::new(1.0, 0.0, 0.0),
+            Vec3::new(3.0, 1.0, 0.0),
+            Vec3::new(3.0, 2.0, 0.0),
+        );
+        assert_eq!(c1, Vec3::new(1.0, 0.0, 0.0));
+        assert_eq!(c2, Vec3::new(3.0, 1.0, 0.0));
+    }
+
+    #[test]
+    fn edge_function_classifies_sides() {
+        // Aresta (0, 0) -> (4, 0); em tela, y negativo fica à esquerda
+        let e = |px, py| edge_functionf(0.0, 0.0, 4.0, 0.0, px, py);
+        assert!(e(2.0, -1.0) > 0.0);
+        assert!(e(2.0, 1.0) < 0.0);
+        assert_eq!(e(7.0, 0.0), 0.0);
+
+        // Módulo é o dobro da área do triângulo
+        assert_eq!(e(1.0, -3.0), 12.0);
+    }
+
+    #[test]
+    fn polygon_area_and_centroid() {
+        let square = [(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)];
+        assert_eq!(polygon_areaf(&square), 1.0);
+        assert_eq!(polygon_centroidf(&square), (0.5, 0.5));
+
+        let tri = [(0.0, 0.0), (4.0, 0.0), (0.0, 3.0)];
+        assert_eq!(polygon_areaf(&tri), 6.0);
+        let (cx, cy) = polygon_centroidf(&tri);
+        assert!(close(cx, 4.0 / 3.0) && close(cy, 1.0));
+
+        // Sentido horário inverte o sinal da área, mas não o centroide
+        let cw = [(0.0, 0.0), (0.0, 3.0), (4.0, 0.0)];
+        assert_eq!(polygon_areaf(&cw), -6.0);
+        let (cx, cy) = polygon_centroidf(&cw);
+        assert!(close(cx, 4.0 / 3.0) && close(cy, 1.0));
+    }
+
+    #[test]
+    fn polygon_with_few_points() {
+        assert_eq!(polygon_areaf(&[(1.0, 2.0), (3.0, 4.0)]), 0.0);
+        assert_eq!(polygon_centroidf(&[(1.0, 2.0), (3.0, 4.0)]), (1.0, 2.0));
+        assert_eq!(polygon_centroidf(&[]), (0.0, 0.0));
+    }
+}
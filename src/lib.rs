@@ -17,22 +17,84 @@
 //! | [`exp`] | Exponencial, logaritmo, potência |
 //! | [`round`] | Arredondamento e truncamento |
 //! | [`util`] | Funções utilitárias (min, max, clamp, lerp) |
+//! | [`rng`] | Números aleatórios e distribuições |
+//! | [`vec`] | Vetores (Vec3) |
+//! | [`geom`] | Interseções e consultas geométricas |
+//! | [`quat`] | Quatérnios para rotações 3D |
+//! | [`mat`] | Matrizes 4x4 column-major |
+//! | [`transform`] | Transformações TRS |
+//! | [`window`] | Funções de janela e kernels para DSP |
+//! | [`curve`] | Splines e curvas para animação |
+//! | [`wave`] | Osciladores e formas de onda |
+//! | [`color`] | Gradientes, conversões de cor e iluminação |
+//! | [`audio`] | Amostras PCM e dinâmica de áudio |
+//! | [`stats`] | Acumuladores e estatística |
+//! | [`approx`] | Tolerâncias e comparação aproximada |
+//! | [`dither`] | Dithering ordenado (Bayer) |
+//! | [`easing`] | Curvas de easing para animação |
+//! | [`complex`] | Números complexos |
+//! | [`fft`] | Transformada rápida de Fourier (radix-2) |
+//! | [`noise`] | Ruído procedural (value noise, Perlin) |
+//! | [`fixed`] | Aritmética de ponto fixo (Q15, Q16.16) |
 
 #![no_std]
 #![allow(dead_code)]
+#![allow(clippy::excessive_precision, clippy::approx_constant)]
 
+// Os testes usam std para valores de referência
+#[cfg(test)]
+extern crate std;
+
+pub mod approx;
+pub mod audio;
+pub mod color;
+pub mod complex;
 pub mod consts;
+pub mod curve;
+pub mod dither;
+pub mod easing;
 pub mod exp;
+pub mod fft;
+pub mod fixed;
+pub mod geom;
+pub mod mat;
+pub mod noise;
+pub mod quat;
+pub mod rng;
 pub mod round;
+pub mod stats;
+pub mod transform;
 pub mod trig;
 pub mod util;
+pub mod vec;
+pub mod wave;
+pub mod window;
 
 // =============================================================================
 // RE-EXPORTS
 // =============================================================================
 
+pub use approx::*;
+pub use audio::*;
+pub use color::*;
+pub use complex::*;
 pub use consts::*;
+pub use curve::*;
+pub use dither::*;
+pub use easing::*;
 pub use exp::*;
+pub use fft::*;
+pub use fixed::*;
+pub use geom::*;
+pub use mat::*;
+pub use noise::*;
+pub use quat::*;
+pub use rng::*;
 pub use round::*;
+pub use stats::*;
+pub use transform::*;
 pub use trig::*;
 pub use util::*;
+pub use vec::*;
+pub use wave::*;
+pub use window::*;
@@ -0,0 +1,401 @@
+//! # Matrizes
+//!
+//! Matrizes 4x4 em ordem column-major (compatível com OpenGL/Vulkan).
+
+use core::ops::Mul;
+
+use crate::quat::Quat;
+use crate::trig::tanf;
+use crate::vec::{Vec3, VecN};
+
+// =============================================================================
+// MAT4
+// =============================================================================
+
+/// Matriz 4x4 column-major.
+///
+/// O elemento da linha `r`, coluna `c` fica no índice `c * 4 + r`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Mat4(pub [f32; 16]);
+
+impl Mat4 {
+    /// Matriz identidade.
+    pub const IDENTITY: Mat4 = Mat4([
+        1.0, 0.0, 0.0, 0.0, //
+        0.0, 1.0, 0.0, 0.0, //
+        0.0, 0.0, 1.0, 0.0, //
+        0.0, 0.0, 0.0, 1.0, //
+    ]);
+
+    /// Matriz identidade.
+    #[inline]
+    pub const fn identity() -> Self {
+        Self::IDENTITY
+    }
+
+    /// Matriz de translação.
+    #[inline]
+    pub const fn translate(t: Vec3) -> Self {
+        let mut m = Self::IDENTITY;
+        m.0[12] = t.x;
+        m.0[13] = t.y;
+        m.0[14] = t.z;
+        m
+    }
+
+    /// Matriz de escala.
+    #[inline]
+    pub const fn scale(s: Vec3) -> Self {
+        let mut m = Self::IDENTITY;
+        m.0[0] = s.x;
+        m.0[5] = s.y;
+        m.0[10] = s.z;
+        m
+    }
+
+    /// Matriz de rotação a partir de um quatérnio unitário.
+    #[inline]
+    pub fn from_quat(q: Quat) -> Self {
+        let (x, y, z, w) = (q.x, q.y, q.z, q.w);
+        let (xx, yy, zz) = (x * x, y * y, z * z);
+        let (xy, xz, yz) = (x * y, x * z, y * z);
+        let (wx, wy, wz) = (w * x, w * y, w * z);
+
+        Mat4([
+            1.0 - 2.0 * (yy + zz),
+            2.0 * (xy + wz),
+            2.0 * (xz - wy),
+            0.0,
+            2.0 * (xy - wz),
+            1.0 - 2.0 * (xx + zz),
+            2.0 * (yz + wx),
+            0.0,
+            2.0 * (xz + wy),
+            2.0 * (yz - wx),
+            1.0 - 2.0 * (xx + yy),
+            0.0,
+            0.0,
+            0.0,
+            0.0,
+            1.0,
+        ])
+    }
+
+    /// Projeção perspectiva (convenção OpenGL, mão direita).
+    ///
+    /// `fovy` é o campo de visão vertical em radianos. A câmera olha para
+    /// -Z e o volume visível vai para z de NDC em [-1, 1], como
+    /// `gluPerspective`. Para Vulkan (z em [0, 1], y para baixo) ajuste a
+    /// matriz resultante.
+    #[inline]
+    pub fn perspective(fovy: f32, aspect: f32, near: f32, far: f32) -> Self {
+        let f = 1.0 / tanf(fovy * 0.5);
+        let nf = 1.0 / (near - far);
+
+        let mut m = Mat4([0.0; 16]);
+        m.0[0] = f / aspect;
+        m.0[5] = f;
+        m.0[10] = (far + near) * nf;
+        m.0[11] = -1.0;
+        m.0[14] = 2.0 * far * near * nf;
+        m
+    }
+
+    /// Projeção ortográfica (convenção OpenGL, como `glOrtho`).
+    ///
+    /// Mapeia a caixa [left, right] × [bottom, top] × [-near, -far] para
+    /// o cubo de NDC [-1, 1]³.
+    #[inline]
+    pub fn ortho(left: f32, right: f32, bottom: f32, top: f32, near: f32, far: f32) -> Self {
+        let rl = 1.0 / (right - left);
+        let tb = 1.0 / (top - bottom);
+        let fnr = 1.0 / (far - near);
+
+        let mut m = Self::IDENTITY;
+        m.0[0] = 2.0 * rl;
+        m.0[5] = 2.0 * tb;
+        m.0[10] = -2.0 * fnr;
+        m.0[12] = -(right + left) * rl;
+        m.0[13] = -(top + bottom) * tb;
+        m.0[14] = -(far + near) * fnr;
+        m
+    }
+
+    /// Matriz de visão de uma câmera em `eye` olhando para `center`.
+    ///
+    /// Mão direita, como `gluLookAt`: a câmera olha para -Z e `up` define
+    /// o +Y da tela (não precisa ser ortogonal à direção de visão, só não
+    /// paralelo a ela).
+    #[inline]
+    pub fn look_at(eye: Vec3, center: Vec3, up: Vec3) -> Self {
+        let f = (center - eye).normalize();
+        let s = f.cross(up).normalize();
+        let u = s.cross(f);
+
+        Mat4([
+            s.x,
+            u.x,
+            -f.x,
+            0.0,
+            s.y,
+            u.y,
+            -f.y,
+            0.0,
+            s.z,
+            u.z,
+            -f.z,
+            0.0,
+            -s.dot(eye),
+            -u.dot(eye),
+            f.dot(eye),
+            1.0,
+        ])
+    }
+
+    /// Elemento na linha `row`, coluna `col`.
+    #[inline]
+    pub const fn get(&self, row: usize, col: usize) -> f32 {
+        self.0[col * 4 + row]
+    }
+}
+
+impl Default for Mat4 {
+    #[inline]
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl Mul for Mat4 {
+    type Output = Mat4;
+
+    #[inline]
+    fn mul(self, rhs: Mat4) -> Mat4 {
+        let a = &self.0;
+        let b = &rhs.0;
+        let mut out = [0.0; 16];
+
+        for c in 0..4 {
+            for r in 0..4 {
+                let mut sum = 0.0;
+                for k in 0..4 {
+                    sum += a[k * 4 + r] * b[c * 4 + k];
+                }
+                out[c * 4 + r] = sum;
+            }
+        }
+
+        Mat4(out)
+    }
+}
+
+/// Produto matriz × vetor coluna (x, y, z, w).
+impl Mul<VecN<4>> for Mat4 {
+    type Output = VecN<4>;
+
+    #[inline]
+    fn mul(self, rhs: VecN<4>) -> VecN<4> {
+        let a = &self.0;
+        let mut out = [0.0; 4];
+
+        for (r, o) in out.iter_mut().enumerate() {
+            for k in 0..4 {
+                *o += a[k * 4 + r] * rhs.0[k];
+            }
+        }
+
+        VecN(out)
+    }
+}
+
+// =============================================================================
+// PROJEÇÃO
+// =============================================================================
+
+/// Projeta um ponto do mundo para coordenadas de tela.
+///
+/// Aplica `view_proj`, divide por w e mapeia o NDC para o `viewport`
+/// `(x, y, largura, altura)`, como `gluProject`: x e y em pixels com
+/// origem no canto inferior esquerdo, z (profundidade) em [0, 1].
+/// Pontos no plano da câmera (w = 0) não têm projeção e dão infinito/NaN.
+#[inline]
+pub fn project(world: Vec3, view_proj: Mat4, viewport: (f32, f32, f32, f32)) -> Vec3 {
+    let clip = view_proj * VecN::new([world.x, world.y, world.z, 1.0]);
+    let inv_w = 1.0 / clip.0[3];
+    let (vx, vy, vw, vh) = viewport;
+
+    Vec3::new(
+        vx + vw * (clip.0[0] * inv_w + 1.0) * 0.5,
+        vy + vh * (clip.0[1] * inv_w + 1.0) * 0.5,
+        (clip.0[2] * inv_w + 1.0) * 0.5,
+    )
+}
+
+/// Inverso de [`project`]: coordenadas de tela de volta para o mundo.
+///
+/// `inv_view_proj` é a inversa da mesma matriz passada a `project`.
+/// Converte `screen` para NDC, aplica a inversa e divide por w. Com z = 0
+/// e z = 1 obtém os pontos nos planos near e far (raio de picking).
+#[inline]
+pub fn unproject(screen: Vec3, inv_view_proj: Mat4, viewport: (f32, f32, f32, f32)) -> Vec3 {
+    let (vx, vy, vw, vh) = viewport;
+    let ndc = VecN::new([
+        2.0 * (screen.x - vx) / vw - 1.0,
+        2.0 * (screen.y - vy) / vh - 1.0,
+        2.0 * screen.z - 1.0,
+        1.0,
+    ]);
+
+    let p = inv_view_proj * ndc;
+    let inv_w = 1.0 / p.0[3];
+    Vec3::new(p.0[0] * inv_w, p.0[1] * inv_w, p.0[2] * inv_w)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::consts::FRAC_PI_2;
+
+    // Inversa por Gauss-Jordan em f64 (só para testes; Mat4 não expõe inversa)
+    fn inverse(m: Mat4) -> Mat4 {
+        let mut a = [[0.0f64; 8]; 4];
+        for (r, row) in a.iter_mut().enumerate() {
+            for (c, v) in row[..4].iter_mut().enumerate() {
+                *v = m.get(r, c) as f64;
+            }
+            row[4 + r] = 1.0;
+        }
+
+        for col in 0..4 {
+            let pivot = (col..4)
+                .max_by(|&i, &j| a[i][col].abs().total_cmp(&a[j][col].abs()))
+                .unwrap();
+            a.swap(col, pivot);
+            let p = a[col][col];
+            for v in a[col].iter_mut() {
+                *v /= p;
+            }
+            let pivot_row = a[col];
+            for (r, row) in a.iter_mut().enumerate() {
+                if r != col {
+                    let k = row[col];
+                    for (v, p) in row.iter_mut().zip(pivot_row.iter()) {
+                        *v -= k * p;
+                    }
+                }
+            }
+        }
+
+        let mut out = Mat4([0.0; 16]);
+        for (r, row) in a.iter().enumerate() {
+            for c in 0..4 {
+                out.0[c * 4 + r] = row[4 + c] as f32;
+            }
+        }
+        out
+    }
+
+    fn sample() -> Mat4 {
+        let mut m = Mat4([0.0; 16]);
+        for (i, v) in m.0.iter_mut().enumerate() {
+            *v = i as f32 * 0.75 - 3.0;
+        }
+        m
+    }
+
+    #[test]
+    fn identity_multiply_is_a_no_op() {
+        let m = sample();
+        assert_eq!(Mat4::IDENTITY * m, m);
+        assert_eq!(m * Mat4::identity(), m);
+        assert_eq!(Mat4::default(), Mat4::IDENTITY);
+
+        let v = VecN::new([1.0, -2.0, 3.5, 1.0]);
+        assert_eq!(Mat4::IDENTITY * v, v);
+    }
+
+    #[test]
+    fn perspective_matches_reference() {
+        let (fovy, aspect, near, far) = (FRAC_PI_2 * 0.75, 16.0 / 9.0, 0.1, 100.0);
+        let m = Mat4::perspective(fovy, aspect, near, far);
+
+        // Fórmula de gluPerspective em f64
+        let f = 1.0 / (fovy as f64 * 0.5).tan();
+        let (n, fa) = (near as f64, far as f64);
+        let mut expected = [0.0f64; 16];
+        expected[0] = f / aspect as f64;
+        expected[5] = f;
+        expected[10] = (fa + n) / (n - fa);
+        expected[11] = -1.0;
+        expected[14] = 2.0 * fa * n / (n - fa);
+
+        for (i, (&got, &want)) in m.0.iter().zip(expected.iter()).enumerate() {
+            assert!(
+                (got as f64 - want).abs() < 1e-5,
+                "[{}]: {} vs {}",
+                i,
+                got,
+                want
+            );
+        }
+
+        // Near e far vão para z de NDC -1 e 1
+        for (z, ndc) in [(-near, -1.0), (-far, 1.0)] {
+            let clip = m * VecN::new([0.0, 0.0, z, 1.0]);
+            assert!((clip.0[2] / clip.0[3] - ndc).abs() < 1e-5);
+        }
+    }
+
+    const VIEWPORT: (f32, f32, f32, f32) = (10.0, 20.0, 1280.0, 720.0);
+
+    fn camera() -> Mat4 {
+        let proj = Mat4::perspective(FRAC_PI_2 * 0.7, 1280.0 / 720.0, 0.5, 200.0);
+        let view = Mat4::look_at(
+            Vec3::new(3.0, 2.0, 8.0),
+            Vec3::new(0.0, 0.5, 0.0),
+            Vec3::new(0.0, 1.0, 0.0),
+        );
+        proj * view
+    }
+
+    #[test]
+    fn project_unproject_round_trip() {
+        let vp = camera();
+        let inv = inverse(vp);
+
+        for p in [
+            Vec3::new(0.0, 0.0, 0.0),
+            Vec3::new(1.5, -0.5, 2.0),
+            Vec3::new(-4.0, 3.0, -10.0),
+            Vec3::new(0.3, 0.7, 5.0),
+        ] {
+            let screen = project(p, vp, VIEWPORT);
+            assert!((0.0..=1.0).contains(&screen.z), "{:?}", screen);
+            let back = unproject(screen, inv, VIEWPORT);
+            let tol = 1e-3 * (1.0 + (p - Vec3::new(3.0, 2.0, 8.0)).length());
+            assert!(
+                (back - p).length() < tol,
+                "{:?} -> {:?} -> {:?}",
+                p,
+                screen,
+                back
+            );
+        }
+    }
+
+    #[test]
+    fn project_maps_look_target_to_viewport_center() {
+        let screen = project(Vec3::new(0.0, 0.5, 0.0), camera(), VIEWPORT);
+        assert!((screen.x - (10.0 + 640.0)).abs() < 1e-3);
+        assert!((screen.y - (20.0 + 360.0)).abs() < 1e-3);
+
+        // z = 0 e z = 1 no centro caem nos planos near e far ao longo do raio
+        let inv = inverse(camera());
+        let eye = Vec3::new(3.0, 2.0, 8.0);
+        let near = unproject(Vec3::new(650.0, 380.0, 0.0), inv, VIEWPORT);
+        let far = unproject(Vec3::new(650.0, 380.0, 1.0), inv, VIEWPORT);
+        assert!(((near - eye).length() - 0.5).abs() < 1e-3);
+        assert!(((far - eye).length() - 200.0).abs() < 0.5);
+    }
+}
@@ -0,0 +1,245 @@
+//! # Ruído Procedural
+//!
+//! Value noise e Perlin noise 2D, determinísticos por semente e sem
+//! alocação: gradientes e valores vêm de um hash da célula.
+
+use crate::exp::powf;
+use crate::round::floorf;
+use crate::util::{lerpf, smoothstepf};
+
+// =============================================================================
+// HASH
+// =============================================================================
+
+/// Hash de 32 bits de uma célula inteira (x, y) e semente.
+///
+/// Mistura com multiplicações ímpares e xorshifts (finalizador estilo
+/// murmur3): células vizinhas produzem valores sem correlação visível.
+#[inline]
+pub const fn hash2(x: i32, y: i32, seed: u32) -> u32 {
+    let mut h = seed ^ (x as u32).wrapping_mul(0x27d4_eb2d);
+    h ^= (y as u32).wrapping_mul(0x1656_67b1);
+    h ^= h >> 16;
+    h = h.wrapping_mul(0x85eb_ca6b);
+    h ^= h >> 13;
+    h = h.wrapping_mul(0xc2b2_ae35);
+    h ^= h >> 16;
+    h
+}
+
+// Célula inteira e posição fracionária dentro dela
+#[inline]
+fn cell(v: f32) -> (i32, f32) {
+    let f = floorf(v);
+    (f as i32, v - f)
+}
+
+// =============================================================================
+// VALUE NOISE
+// =============================================================================
+
+/// Value noise 2D em [-1, 1].
+///
+/// Cada canto inteiro recebe um valor aleatório (hash da célula) e o
+/// interior é interpolado com `smoothstepf`. Nos cantos o resultado é
+/// exatamente o valor do canto e a derivada da curva é zero nas bordas,
+/// então não há emendas entre células.
+#[inline]
+pub fn value_noise_2d(x: f32, y: f32, seed: u32) -> f32 {
+    let (ix, fx) = cell(x);
+    let (iy, fy) = cell(y);
+
+    let v00 = corner_value(ix, iy, seed);
+    let v10 = corner_value(ix.wrapping_add(1), iy, seed);
+    let v01 = corner_value(ix, iy.wrapping_add(1), seed);
+    let v11 = corner_value(ix.wrapping_add(1), iy.wrapping_add(1), seed);
+
+    let u = smoothstepf(0.0, 1.0, fx);
+    let v = smoothstepf(0.0, 1.0, fy);
+
+    lerpf(lerpf(v00, v10, u), lerpf(v01, v11, u), v)
+}
+
+// Valor do canto em [-1, 1] a partir dos 24 bits altos do hash
+#[inline]
+fn corner_value(x: i32, y: i32, seed: u32) -> f32 {
+    (hash2(x, y, seed) >> 8) as f32 * (2.0 / 16777215.0) - 1.0
+}
+
+// =============================================================================
+// PERLIN NOISE
+// =============================================================================
+
+/// Perlin noise (gradiente) 2D em [-1, 1].
+///
+/// Cada canto recebe um de 8 gradientes escolhido pelo hash; o valor é a
+/// interpolação com `smoothstepf` dos produtos escalares entre gradiente e
+/// offset. Vale zero em todo ponto inteiro e é contínuo entre células.
+#[inline]
+pub fn perlin_2d(x: f32, y: f32, seed: u32) -> f32 {
+    let (ix, fx) = cell(x);
+    let (iy, fy) = cell(y);
+
+    let g00 = grad(hash2(ix, iy, seed), fx, fy);
+    let g10 = grad(hash2(ix.wrapping_add(1), iy, seed), fx - 1.0, fy);
+    let g01 = grad(hash2(ix, iy.wrapping_add(1), seed), fx, fy - 1.0);
+    let g11 = grad(
+        hash2(ix.wrapping_add(1), iy.wrapping_add(1), seed),
+        fx - 1.0,
+        fy - 1.0,
+    );
+
+    let u = smoothstepf(0.0, 1.0, fx);
+    let v = smoothstepf(0.0, 1.0, fy);
+
+    lerpf(lerpf(g00, g10, u), lerpf(g01, g11, u), v)
+}
+
+// Produto escalar do offset (dx, dy) com um dos 8 gradientes
+// (±1, ±1), (±1, 0), (0, ±1)
+#[inline]
+fn grad(h: u32, dx: f32, dy: f32) -> f32 {
+    match h >> 29 {
+        0 => dx + dy,
+        1 => dx - dy,
+        2 => -dx + dy,
+        3 => -dx - dy,
+        4 => dx,
+        5 => -dx,
+        6 => dy,
+        _ => -dy,
+    }
+}
+
+// =============================================================================
+// FBM
+// =============================================================================
+
+/// Movimento browniano fracionário: soma de oitavas de `perlin_2d`.
+///
+/// A oitava i tem frequência `lacunarity^i` e amplitude `gain^i`
+/// (tipicamente 2.0 e 0.5). A soma é dividida pela soma das amplitudes,
+/// então o resultado fica em [-1, 1] para qualquer número de oitavas;
+/// mais oitavas só acrescentam detalhe fino. Cada oitava usa uma semente
+/// derivada para não alinhar os padrões. `octaves == 0` retorna 0.
+#[inline]
+pub fn fbm2(x: f32, y: f32, octaves: u32, lacunarity: f32, gain: f32, seed: u32) -> f32 {
+    let mut sum = 0.0;
+    let mut norm = 0.0;
+
+    for i in 0..octaves {
+        let freq = powf(lacunarity, i as f32);
+        let amp = powf(gain, i as f32);
+        sum += amp * perlin_2d(x * freq, y * freq, seed.wrapping_add(i));
+        norm += amp;
+    }
+
+    if norm > 0.0 {
+        sum / norm
+    } else {
+        0.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Grade de pontos cobrindo coordenadas negativas e positivas
+    fn grid(mut f: impl FnMut(f32, f32)) {
+        for j in -40..40 {
+            for i in -40..40 {
+                f(i as f32 * 0.173 + 0.01, j as f32 * 0.219 - 0.02);
+            }
+        }
+    }
+
+    #[test]
+    fn noise_stays_in_range_and_is_deterministic() {
+        for seed in [0, 1, 0xdead_beef] {
+            grid(|x, y| {
+                let v = value_noise_2d(x, y, seed);
+                let p = perlin_2d(x, y, seed);
+                assert!((-1.0..=1.0).contains(&v), "value({x}, {y}) = {v}");
+                assert!((-1.0..=1.0).contains(&p), "perlin({x}, {y}) = {p}");
+                assert_eq!(v, value_noise_2d(x, y, seed));
+                assert_eq!(p, perlin_2d(x, y, seed));
+            });
+        }
+    }
+
+    #[test]
+    fn noise_depends_on_seed() {
+        let mut differs = 0;
+        grid(|x, y| {
+            if value_noise_2d(x, y, 1) != value_noise_2d(x, y, 2) {
+                differs += 1;
+            }
+        });
+        assert!(differs > 6000, "{differs}");
+    }
+
+    #[test]
+    fn noise_is_continuous_across_cell_boundaries() {
+        let d = 1e-3;
+        for seed in [3, 77] {
+            for k in -5..5 {
+                let b = k as f32;
+                for t in [0.1, 0.45, 0.9] {
+                    // Atravessando x inteiro e y inteiro
+                    for (a0, a1) in [((b - d, t), (b + d, t)), ((t, b - d), (t, b + d))] {
+                        let dv =
+                            value_noise_2d(a0.0, a0.1, seed) - value_noise_2d(a1.0, a1.1, seed);
+                        let dp = perlin_2d(a0.0, a0.1, seed) - perlin_2d(a1.0, a1.1, seed);
+                        assert!(dv.abs() < 1e-2, "value: salto {dv} em {b}");
+                        assert!(dp.abs() < 1e-2, "perlin: salto {dp} em {b}");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn perlin_is_zero_on_lattice_points() {
+        for j in -3..3 {
+            for i in -3..3 {
+                assert_eq!(perlin_2d(i as f32, j as f32, 9), 0.0);
+            }
+        }
+    }
+
+    // Variação total ao longo de uma linha: cresce com o detalhe fino
+    fn roughness(octaves: u32) -> f32 {
+        let mut total = 0.0;
+        let mut prev = fbm2(0.0, 0.37, octaves, 2.0, 0.5, 5);
+        for i in 1..2000 {
+            let v = fbm2(i as f32 * 0.005, 0.37, octaves, 2.0, 0.5, 5);
+            total += (v - prev).abs();
+            prev = v;
+        }
+        total
+    }
+
+    #[test]
+    fn fbm_stays_bounded_for_any_octave_count() {
+        for octaves in [1, 2, 4, 8] {
+            grid(|x, y| {
+                let v = fbm2(x, y, octaves, 2.0, 0.5, 11);
+                assert!((-1.0..=1.0).contains(&v), "fbm({x}, {y}, {octaves}) = {v}");
+                assert_eq!(v, fbm2(x, y, octaves, 2.0, 0.5, 11));
+            });
+        }
+        assert_eq!(fbm2(0.3, 0.4, 0, 2.0, 0.5, 11), 0.0);
+        // Uma oitava é o próprio perlin
+        assert_eq!(fbm2(0.3, 0.4, 1, 2.0, 0.5, 11), perlin_2d(0.3, 0.4, 11));
+    }
+
+    #[test]
+    fn fbm_more_octaves_add_detail() {
+        let r1 = roughness(1);
+        let r3 = roughness(3);
+        let r6 = roughness(6);
+        assert!(r3 > r1 * 1.2, "{r1} -> {r3}");
+        assert!(r6 > r3, "{r3} -> {r6}");
+    }
+}
@@ -0,0 +1,239 @@
+//! # Quatérnios
+//!
+//! Rotações 3D representadas por quatérnios unitários.
+
+use core::ops::Mul;
+
+use crate::exp::rsqrtf;
+use crate::trig::{acosf, sincosf, sinf};
+use crate::vec::Vec3;
+
+// =============================================================================
+// QUAT
+// =============================================================================
+
+/// Quatérnio `w + xi + yj + zk`.
+///
+/// Rotações usam quatérnios unitários.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Quat {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+    pub w: f32,
+}
+
+impl Quat {
+    /// Cria um quatérnio a partir dos componentes.
+    #[inline]
+    pub const fn new(x: f32, y: f32, z: f32, w: f32) -> Self {
+        Self { x, y, z, w }
+    }
+
+    /// Rotação nula.
+    #[inline]
+    pub const fn identity() -> Self {
+        Self::new(0.0, 0.0, 0.0, 1.0)
+    }
+
+    /// Rotação de `angle` radianos em torno de `axis` (regra da mão direita).
+    ///
+    /// O eixo é normalizado aqui; eixo nulo resulta na identidade.
+    #[inline]
+    pub fn from_axis_angle(axis: Vec3, angle: f32) -> Quat {
+        let axis = axis.normalize();
+        if axis.length_squared() == 0.0 {
+            return Quat::identity();
+        }
+
+        let (s, c) = sincosf(angle * 0.5);
+        Quat::new(axis.x * s, axis.y * s, axis.z * s, c)
+    }
+
+    /// Conjugado (inverso de um quatérnio unitário).
+    #[inline]
+    pub fn conjugate(self) -> Quat {
+        Quat::new(-self.x, -self.y, -self.z, self.w)
+    }
+
+    /// Produto escalar (cosseno de metade do ângulo entre rotações).
+    #[inline]
+    pub fn dot(self, other: Quat) -> f32 {
+        self.x * other.x + self.y * other.y + self.z * other.z + self.w * other.w
+    }
+
+    /// Normaliza para comprimento 1. Quatérnio nulo retorna identidade.
+    #[inline]
+    pub fn normalize(self) -> Quat {
+        let len_sq = self.dot(self);
+        if len_sq == 0.0 {
+            return Quat::identity();
+        }
+        let inv = rsqrtf(len_sq);
+        Quat::new(self.x * inv, self.y * inv, self.z * inv, self.w * inv)
+    }
+
+    /// Interpolação esférica pelo caminho mais curto.
+    ///
+    /// Como `q` e `-q` representam a mesma rotação, inverte `other` quando
+    /// o produto escalar é negativo para não girar pelo caminho longo.
+    /// Para quatérnios quase paralelos cai para nlerp. t=0 retorna `self`
+    /// e t=1 retorna `other` (ou `-other`).
+    #[inline]
+    pub fn slerp(self, other: Quat, t: f32) -> Quat {
+        let mut d = self.dot(other);
+        let mut b = other;
+        if d < 0.0 {
+            d = -d;
+            b = Quat::new(-b.x, -b.y, -b.z, -b.w);
+        }
+
+        // Pontas exatas
+        if t == 0.0 {
+            return self;
+        }
+        if t == 1.0 {
+            return b;
+        }
+
+        let (wa, wb) = if d > SLERP_NLERP_THRESHOLD {
+            (1.0 - t, t)
+        } else {
+            let theta = acosf(d);
+            let inv_sin = 1.0 / sinf(theta);
+            (sinf((1.0 - t) * theta) * inv_sin, sinf(t * theta) * inv_sin)
+        };
+
+        // Normaliza para absorver o erro das aproximações trigonométricas
+        Quat::new(
+            wa * self.x + wb * b.x,
+            wa * self.y + wb * b.y,
+            wa * self.z + wb * b.z,
+            wa * self.w + wb * b.w,
+        )
+        .normalize()
+    }
+
+    /// Rotaciona um vetor (assume quatérnio unitário).
+    #[inline]
+    pub fn rotate_vec3(self, v: Vec3) -> Vec3 {
+        // v' = v + 2w(u × v) + 2u × (u × v), com u = (x, y, z)
+        let u = Vec3::new(self.x, self.y, self.z);
+        let t = u.cross(v).scale(2.0);
+
+        v + t.scale(self.w) + u.cross(t)
+    }
+}
+
+// Acima deste produto escalar, slerp usa nlerp (sin(θ) ≈ 0)
+const SLERP_NLERP_THRESHOLD: f32 = 0.9995;
+
+impl Default for Quat {
+    #[inline]
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+/// Produto de Hamilton: `a * b` aplica `b` primeiro, depois `a`.
+impl Mul for Quat {
+    type Output = Quat;
+
+    #[inline]
+    fn mul(self, rhs: Quat) -> Quat {
+        let (a, b) = (self, rhs);
+        Quat::new(
+            a.w * b.x + a.x * b.w + a.y * b.z - a.z * b.y,
+            a.w * b.y - a.x * b.z + a.y * b.w + a.z * b.x,
+            a.w * b.z + a.x * b.y - a.y * b.x + a.z * b.w,
+            a.w * b.w - a.x * b.x - a.y * b.y - a.z * b.z,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::consts::{FRAC_PI_2, PI};
+
+    fn neg(q: Quat) -> Quat {
+        Quat::new(-q.x, -q.y, -q.z, -q.w)
+    }
+
+    fn close_quat(a: Quat, b: Quat, tol: f32) -> bool {
+        (a.x - b.x).abs() < tol
+            && (a.y - b.y).abs() < tol
+            && (a.z - b.z).abs() < tol
+            && (a.w - b.w).abs() < tol
+    }
+
+    #[test]
+    fn slerp_endpoints_are_exact() {
+        let a = Quat::from_axis_angle(Vec3::new(0.0, 0.0, 1.0), 0.3);
+        let b = Quat::from_axis_angle(Vec3::new(0.0, 1.0, 0.0), 1.2);
+        assert!(a.dot(b) > 0.0);
+        assert_eq!(a.slerp(b, 0.0), a);
+        assert_eq!(a.slerp(b, 1.0), b);
+    }
+
+    #[test]
+    fn slerp_end_is_negated_when_dot_is_negative() {
+        let a = Quat::from_axis_angle(Vec3::new(0.0, 0.0, 1.0), 0.3);
+        let b = neg(Quat::from_axis_angle(Vec3::new(0.0, 1.0, 0.0), 1.2));
+        assert!(a.dot(b) < 0.0);
+        assert_eq!(a.slerp(b, 0.0), a);
+        assert_eq!(a.slerp(b, 1.0), neg(b));
+    }
+
+    #[test]
+    fn slerp_to_negated_self_does_not_spin() {
+        // q e -q são a mesma rotação: todo t deve devolver q
+        let q = Quat::from_axis_angle(Vec3::new(1.0, 2.0, 3.0).normalize(), 2.5);
+        let v = Vec3::new(0.3, -1.0, 0.7);
+        let expected = q.rotate_vec3(v);
+        for i in 0..=10 {
+            let s = q.slerp(neg(q), i as f32 / 10.0);
+            assert!(close_quat(s, q, 1e-5), "t = {}: {:?}", i, s);
+            let r = s.rotate_vec3(v);
+            assert!((r - expected).length() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn slerp_midpoint_takes_short_path() {
+        let z = Vec3::new(0.0, 0.0, 1.0);
+        let a = Quat::identity();
+        let b = Quat::from_axis_angle(z, FRAC_PI_2);
+        let half = Quat::from_axis_angle(z, FRAC_PI_2 / 2.0);
+        assert!(close_quat(a.slerp(b, 0.5), half, 1e-4));
+        // Com o sinal trocado a meia-volta continua sendo 45°
+        assert!(close_quat(a.slerp(neg(b), 0.5), half, 1e-4));
+    }
+
+    #[test]
+    fn two_quarter_turns_about_z_make_a_half_turn() {
+        let z = Vec3::new(0.0, 0.0, 1.0);
+        let q90 = Quat::from_axis_angle(z, FRAC_PI_2);
+        let q180 = Quat::from_axis_angle(z, PI);
+        assert!(close_quat(q90 * q90, q180, 1e-5), "{:?}", q90 * q90);
+
+        let v = (q90 * q90).rotate_vec3(Vec3::new(1.0, 2.0, 3.0));
+        assert!((v.x + 1.0).abs() < 1e-4 && (v.y + 2.0).abs() < 1e-4 && (v.z - 3.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn quarter_turn_about_z_maps_x_to_y() {
+        let q = Quat::from_axis_angle(Vec3::new(0.0, 0.0, 1.0), FRAC_PI_2);
+        let v = q.rotate_vec3(Vec3::new(1.0, 0.0, 0.0));
+        assert!(
+            v.x.abs() < 1e-4 && (v.y - 1.0).abs() < 1e-4 && v.z.abs() < 1e-4,
+            "{:?}",
+            v
+        );
+
+        // Identidade e conjugado desfazem a rotação
+        assert_eq!(Quat::identity().rotate_vec3(v), v);
+        let back = q.conjugate().rotate_vec3(v);
+        assert!((back.x - 1.0).abs() < 1e-4 && back.y.abs() < 1e-4);
+    }
+}
@@ -0,0 +1,362 @@
+//! # Números Aleatórios
+//!
+//! Geradores pseudoaleatórios e amostragem de distribuições.
+//! Tudo determinístico e sem alocação.
+
+use crate::consts::TAU;
+use crate::exp::{logf, sqrtf};
+use crate::trig::sincosf;
+use crate::util::lerpf;
+
+// =============================================================================
+// TRAIT
+// =============================================================================
+
+/// Fonte de números pseudoaleatórios.
+///
+/// Basta implementar `next_u32`; os demais métodos derivam dele.
+pub trait Rng {
+    /// Próximo inteiro de 32 bits uniformemente distribuído.
+    fn next_u32(&mut self) -> u32;
+
+    /// Próximo float uniforme em [0, 1).
+    #[inline]
+    fn next_f32(&mut self) -> f32 {
+        // 24 bits superiores cabem exatamente na mantissa
+        (self.next_u32() >> 8) as f32 * (1.0 / 16777216.0)
+    }
+
+    /// Próximo float uniforme entre `min` e `max`.
+    ///
+    /// Interpola com `lerpf`; por arredondamento o resultado pode
+    /// coincidir com `max` quando o intervalo é grande.
+    #[inline]
+    fn range_f32(&mut self, min: f32, max: f32) -> f32 {
+        lerpf(min, max, self.next_f32())
+    }
+}
+
+// =============================================================================
+// PCG32
+// =============================================================================
+
+/// Gerador PCG32 (XSH RR) de O'Neill: 64 bits de estado, saída de 32 bits.
+///
+/// Rápido, com boa qualidade estatística e período 2^64. A mesma semente
+/// sempre produz a mesma sequência. Não é criptograficamente seguro.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Pcg32 {
+    state: u64,
+}
+
+// Multiplicador do LCG e incremento (stream) padrão da implementação
+// de referência
+const PCG_MULT: u64 = 6364136223846793005;
+const PCG_INC: u64 = 1442695040888963407;
+
+impl Pcg32 {
+    /// Cria o gerador a partir de uma semente.
+    #[inline]
+    pub const fn new(seed: u64) -> Self {
+        // Mesma inicialização de pcg32_srandom: parte do estado 0, avança
+        // (estado = incremento), soma a semente e avança de novo
+        let state = PCG_INC.wrapping_add(seed);
+        Self {
+            state: state.wrapping_mul(PCG_MULT).wrapping_add(PCG_INC),
+        }
+    }
+}
+
+impl Rng for Pcg32 {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        let old = self.state;
+        self.state = old.wrapping_mul(PCG_MULT).wrapping_add(PCG_INC);
+
+        // Permutação de saída: xorshift alto + rotação dependente do estado
+        let xorshifted = (((old >> 18) ^ old) >> 27) as u32;
+        let rot = (old >> 59) as u32;
+        xorshifted.rotate_right(rot)
+    }
+}
+
+// =============================================================================
+// DISTRIBUIÇÃO NORMAL
+// =============================================================================
+
+/// Par de amostras normais independentes (média 0, variância 1).
+///
+/// Usa a transformada de Box-Muller.
+#[inline]
+pub fn next_gaussian_pairf<R: Rng + ?Sized>(rng: &mut R) -> (f32, f32) {
+    // u1 em (0, 1] para evitar log(0)
+    let u1 = 1.0 - rng.next_f32();
+    let u2 = rng.next_f32();
+
+    let r = sqrtf(-2.0 * logf(u1));
+    let (s, c) = sincosf(TAU * u2);
+
+    (r * c, r * s)
+}
+
+/// Amostra normal (média 0, variância 1).
+///
+/// Descarta o segundo valor do Box-Muller; use [`GaussianSampler`]
+/// para aproveitá-lo.
+#[inline]
+pub fn next_gaussianf<R: Rng + ?Sized>(rng: &mut R) -> f32 {
+    next_gaussian_pairf(rng).0
+}
+
+/// Amostrador normal que guarda o segundo valor do Box-Muller.
+///
+/// Metade das chamadas não consome o gerador.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct GaussianSampler {
+    cached: Option<f32>,
+}
+
+impl GaussianSampler {
+    /// Cria amostrador sem valor em cache.
+    #[inline]
+    pub const fn new() -> Self {
+        Self { cached: None }
+    }
+
+    /// Próxima amostra normal (média 0, variância 1).
+    #[inline]
+    pub fn next<R: Rng + ?Sized>(&mut self, rng: &mut R) -> f32 {
+        if let Some(z) = self.cached.take() {
+            return z;
+        }
+
+        let (z0, z1) = next_gaussian_pairf(rng);
+        self.cached = Some(z1);
+        z0
+    }
+}
+
+// =============================================================================
+// AMOSTRAGEM GEOMÉTRICA
+// =============================================================================
+
+/// Ponto uniforme (por área) dentro do disco unitário.
+///
+/// Usa rejeição no quadrado [-1, 1]²: aceita ~78.5% das tentativas
+/// e não depende da precisão de `sincosf`.
+#[inline]
+pub fn random_in_unit_diskf<R: Rng + ?Sized>(rng: &mut R) -> (f32, f32) {
+    loop {
+        let x = 2.0 * rng.next_f32() - 1.0;
+        let y = 2.0 * rng.next_f32() - 1.0;
+        if x * x + y * y < 1.0 {
+            return (x, y);
+        }
+    }
+}
+
+/// Ponto uniforme na superfície da esfera unitária.
+///
+/// Método de Marsaglia: a partir de (u, v) no disco, z = 1 - 2s com
+/// s = u² + v², que é uniforme em [-1, 1]. Equivale a amostrar
+/// θ = acos(1 - 2s), evitando o acúmulo de pontos nos polos de um θ
+/// uniforme, sem chamar funções trigonométricas.
+#[inline]
+pub fn random_on_unit_spheref<R: Rng + ?Sized>(rng: &mut R) -> (f32, f32, f32) {
+    let (u, v) = random_in_unit_diskf(rng);
+    let s = u * u + v * v;
+    let k = 2.0 * sqrtf(1.0 - s);
+
+    (u * k, v * k, 1.0 - 2.0 * s)
+}
+
+// =============================================================================
+// SELEÇÃO PONDERADA
+// =============================================================================
+
+/// Sorteia um índice com probabilidade proporcional ao seu peso.
+///
+/// Pesos negativos contam como zero. Se a soma dos pesos for zero,
+/// escolhe um índice uniformemente. Slice vazia retorna 0.
+#[inline]
+pub fn weighted_choice<R: Rng + ?Sized>(rng: &mut R, weights: &[f32]) -> usize {
+    if weights.is_empty() {
+        return 0;
+    }
+
+    let mut total = 0.0;
+    for &w in weights {
+        if w > 0.0 {
+            total += w;
+        }
+    }
+
+    if total <= 0.0 {
+        let i = (rng.next_f32() * weights.len() as f32) as usize;
+        return i.min(weights.len() - 1);
+    }
+
+    // Uniforme em [0, total) contra a soma acumulada
+    let target = rng.next_f32() * total;
+    let mut acc = 0.0;
+    let mut last = 0;
+    for (i, &w) in weights.iter().enumerate() {
+        if w > 0.0 {
+            acc += w;
+            last = i;
+            if target < acc {
+                return i;
+            }
+        }
+    }
+
+    // Arredondamento da soma: fica com o último índice de peso positivo
+    last
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLES: usize = 200_000;
+
+    // Média e variância populacional das amostras
+    fn mean_var(mut next: impl FnMut() -> f32) -> (f64, f64) {
+        let (mut sum, mut sum_sq) = (0.0f64, 0.0f64);
+        for _ in 0..SAMPLES {
+            let z = next() as f64;
+            sum += z;
+            sum_sq += z * z;
+        }
+        let mean = sum / SAMPLES as f64;
+        (mean, sum_sq / SAMPLES as f64 - mean * mean)
+    }
+
+    #[test]
+    fn gaussian_has_zero_mean_unit_variance() {
+        let mut rng = Pcg32::new(458);
+        let (mean, var) = mean_var(|| next_gaussianf(&mut rng));
+        assert!(mean.abs() < 0.01, "média {mean}");
+        assert!((var - 1.0).abs() < 0.02, "variância {var}");
+    }
+
+    #[test]
+    fn gaussian_sampler_uses_both_values() {
+        let mut rng = Pcg32::new(459);
+        let mut sampler = GaussianSampler::new();
+        let (mean, var) = mean_var(|| sampler.next(&mut rng));
+        assert!(mean.abs() < 0.01, "média {mean}");
+        assert!((var - 1.0).abs() < 0.02, "variância {var}");
+
+        // O segundo valor do par vem do cache, sem consumir o gerador
+        let mut a = Pcg32::new(1);
+        let mut b = Pcg32::new(1);
+        let mut sampler = GaussianSampler::new();
+        let (z0, z1) = next_gaussian_pairf(&mut a);
+        assert_eq!(sampler.next(&mut b), z0);
+        assert_eq!(sampler.next(&mut b), z1);
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn sphere_points_are_unit_length() {
+        let mut rng = Pcg32::new(460);
+        let mut z_sum = 0.0f64;
+        for _ in 0..SAMPLES {
+            let (x, y, z) = random_on_unit_spheref(&mut rng);
+            let len = (x * x + y * y + z * z).sqrt();
+            assert!((len - 1.0).abs() < 1e-5, "comprimento {len}");
+            z_sum += z as f64;
+        }
+        // z uniforme em [-1, 1]: sem acúmulo em um dos polos
+        assert!((z_sum / SAMPLES as f64).abs() < 0.01);
+    }
+
+    #[test]
+    fn disk_points_are_uniform_by_area() {
+        let mut rng = Pcg32::new(461);
+        let mut inner = 0;
+        for _ in 0..SAMPLES {
+            let (x, y) = random_in_unit_diskf(&mut rng);
+            let r2 = x * x + y * y;
+            assert!(r2 < 1.0);
+            if r2 < 0.25 {
+                inner += 1;
+            }
+        }
+        // O disco de raio 0.5 tem 1/4 da área
+        let frac = inner as f64 / SAMPLES as f64;
+        assert!((frac - 0.25).abs() < 0.01, "fração interna {frac}");
+    }
+
+    #[test]
+    fn weighted_choice_matches_weights() {
+        let mut rng = Pcg32::new(462);
+        let weights = [1.0, 0.0, 3.0, -2.0, 6.0];
+        let mut counts = [0usize; 5];
+        for _ in 0..SAMPLES {
+            counts[weighted_choice(&mut rng, &weights)] += 1;
+        }
+
+        // Pesos zero e negativos nunca saem
+        assert_eq!(counts[1], 0);
+        assert_eq!(counts[3], 0);
+        for (i, expected) in [(0, 0.1), (2, 0.3), (4, 0.6)] {
+            let freq = counts[i] as f64 / SAMPLES as f64;
+            assert!((freq - expected).abs() < 0.01, "índice {i}: {freq}");
+        }
+    }
+
+    #[test]
+    fn weighted_choice_degenerate_inputs() {
+        let mut rng = Pcg32::new(463);
+        assert_eq!(weighted_choice(&mut rng, &[]), 0);
+
+        // Soma zero: uniforme sobre todos os índices
+        let mut seen = [false; 4];
+        for _ in 0..1000 {
+            seen[weighted_choice(&mut rng, &[0.0; 4])] = true;
+        }
+        assert!(seen.iter().all(|&s| s));
+    }
+
+    #[test]
+    fn pcg32_matches_reference_sequence() {
+        // pcg32_srandom + pcg32_random da implementação de referência,
+        // com o incremento padrão
+        let mut rng = Pcg32::new(42);
+        let expected = [
+            0xc2f57bd6, 0x6b07c4a9, 0x72b7b29b, 0x44215383, 0xf5af5ead, 0x68beb632,
+        ];
+        for e in expected {
+            assert_eq!(rng.next_u32(), e);
+        }
+
+        let mut rng = Pcg32::new(0);
+        assert_eq!(rng.next_u32(), 0xe823a24e);
+        assert_eq!(rng.next_u32(), 0x7a7ecbd9);
+    }
+
+    #[test]
+    fn pcg32_is_reproducible() {
+        let mut a = Pcg32::new(1234);
+        let mut b = Pcg32::new(1234);
+        let mut c = Pcg32::new(1235);
+        let mut differs = false;
+        for _ in 0..1000 {
+            let x = a.next_u32();
+            assert_eq!(x, b.next_u32());
+            differs |= x != c.next_u32();
+        }
+        assert!(differs);
+    }
+
+    #[test]
+    fn pcg32_next_f32_is_in_unit_interval() {
+        let mut rng = Pcg32::new(7);
+        for _ in 0..SAMPLES {
+            let v = rng.next_f32();
+            assert!((0.0..1.0).contains(&v), "{}", v);
+        }
+    }
+}
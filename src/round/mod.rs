@@ -6,11 +6,19 @@
 // FLOOR / CEIL / ROUND / TRUNC
 // =============================================================================
 
+// A partir de 2^23 todo f32 já é inteiro (e pode não caber em i32)
+const INTEGRAL_THRESHOLD: f32 = 8388608.0;
+
 /// Arredonda para baixo (floor).
 ///
-/// Retorna o maior inteiro menor ou igual ao valor.
+/// Retorna o maior inteiro menor ou igual ao valor. Valores com
+/// |x| >= 2^23, infinitos e NaN são retornados inalterados.
 #[inline]
 pub fn floorf(x: f32) -> f32 {
+    if x.is_nan() || absf(x) >= INTEGRAL_THRESHOLD {
+        return x;
+    }
+
     let xi = x as i32;
     let xf = xi as f32;
     if x < xf {
@@ -22,9 +30,14 @@ pub fn floorf(x: f32) -> f32 {
 
 /// Arredonda para cima (ceil).
 ///
-/// Retorna o menor inteiro maior ou igual ao valor.
+/// Retorna o menor inteiro maior ou igual ao valor. Valores com
+/// |x| >= 2^23, infinitos e NaN são retornados inalterados.
 #[inline]
 pub fn ceilf(x: f32) -> f32 {
+    if x.is_nan() || absf(x) >= INTEGRAL_THRESHOLD {
+        return x;
+    }
+
     let xi = x as i32;
     let xf = xi as f32;
     if x > xf {
@@ -36,19 +49,47 @@ pub fn ceilf(x: f32) -> f32 {
 
 /// Arredonda para o inteiro mais próximo.
 ///
-/// Usa arredondamento "half away from zero" (0.5 -> 1, -0.5 -> -1).
+/// Empates vão para longe do zero (0.5 -> 1, -0.5 -> -1, 2.5 -> 3); veja
+/// `roundf_ties_even` para arredondamento bancário. Compara a parte
+/// fracionária exata em vez de somar 0.5, que arredondaria 0.49999997
+/// para 1.
 #[inline]
 pub fn roundf(x: f32) -> f32 {
-    if x >= 0.0 {
-        floorf(x + 0.5)
+    let t = truncf(x);
+    let d = x - t;
+    if d >= 0.5 {
+        t + 1.0
+    } else if d <= -0.5 {
+        t - 1.0
+    } else {
+        t
+    }
+}
+
+/// Arredonda para o inteiro mais próximo, empates para o par.
+///
+/// Arredondamento bancário / IEEE-754 padrão: 0.5 -> 0, 1.5 -> 2,
+/// 2.5 -> 2, -0.5 -> 0.
+#[inline]
+pub fn roundf_ties_even(x: f32) -> f32 {
+    let t = truncf(x);
+    let d = x - t;
+    let a = absf(d);
+
+    // |t| < 2^23 aqui (senão d == 0), então cabe em i32
+    if a > 0.5 || (a == 0.5 && (t as i32) & 1 != 0) {
+        t + copysignf(1.0, d)
     } else {
-        ceilf(x - 0.5)
+        t
     }
 }
 
 /// Trunca para inteiro (remove parte decimal).
 #[inline]
 pub fn truncf(x: f32) -> f32 {
+    if x.is_nan() || absf(x) >= INTEGRAL_THRESHOLD {
+        return x;
+    }
     x as i32 as f32
 }
 
@@ -58,6 +99,60 @@ pub fn fractf(x: f32) -> f32 {
     x - floorf(x)
 }
 
+/// Floor manipulando diretamente os bits da mantissa.
+///
+/// Não converte para inteiro, então vale em toda a faixa de f32:
+/// |x| >= 2^23, infinitos e NaN retornam inalterados e zeros mantêm
+/// o sinal.
+#[inline]
+pub fn floorf_bits(x: f32) -> f32 {
+    match trunc_bits(x) {
+        Some(t) if x < t => t - 1.0,
+        Some(t) => t,
+        None => x,
+    }
+}
+
+/// Ceil manipulando diretamente os bits da mantissa.
+///
+/// Mesmas garantias de [`floorf_bits`].
+#[inline]
+pub fn ceilf_bits(x: f32) -> f32 {
+    match trunc_bits(x) {
+        Some(t) if x > t => t + 1.0,
+        Some(t) => t,
+        None => x,
+    }
+}
+
+// Trunca zerando os bits fracionários da mantissa. `None` quando x já é
+// inteiro (incluindo zeros, |x| >= 2^23, infinitos e NaN)
+#[inline]
+fn trunc_bits(x: f32) -> Option<f32> {
+    let bits = x.to_bits();
+    let e = ((bits >> 23) & 0xff) as i32 - 127;
+
+    if e >= 23 {
+        return None;
+    }
+
+    if e < 0 {
+        // |x| < 1: trunca para zero com o sinal de x
+        return if bits << 1 == 0 {
+            None
+        } else {
+            Some(f32::from_bits(bits & 0x8000_0000))
+        };
+    }
+
+    let mask = 0x007f_ffffu32 >> e;
+    if bits & mask == 0 {
+        None
+    } else {
+        Some(f32::from_bits(bits & !mask))
+    }
+}
+
 // =============================================================================
 // ABS / SIGN / COPYSIGN
 // =============================================================================
@@ -85,14 +180,38 @@ pub fn signf(x: f32) -> f32 {
 }
 
 /// Copia o sinal de y para x.
+///
+/// Operação puramente de bits (IEEE-754): respeita o sinal de ±0.0 e
+/// de NaN em `y`, e preserva o payload de NaN em `x`.
 #[inline]
-pub fn copysignf(x: f32, y: f32) -> f32 {
-    let abs_x = absf(x);
-    if y >= 0.0 {
-        abs_x
-    } else {
-        -abs_x
-    }
+pub const fn copysignf(x: f32, y: f32) -> f32 {
+    const SIGN: u32 = 0x8000_0000;
+    f32::from_bits((x.to_bits() & !SIGN) | (y.to_bits() & SIGN))
+}
+
+// =============================================================================
+// CLASSIFICAÇÃO
+// =============================================================================
+
+const EXP_MASK: u32 = 0x7f80_0000;
+const ABS_MASK: u32 = 0x7fff_ffff;
+
+/// Verdadeiro se x não é infinito nem NaN (expoente diferente de 255).
+#[inline]
+pub const fn isfinitef(x: f32) -> bool {
+    x.to_bits() & EXP_MASK != EXP_MASK
+}
+
+/// Verdadeiro se x é NaN (expoente 255 e mantissa não nula).
+#[inline]
+pub const fn isnanf(x: f32) -> bool {
+    x.to_bits() & ABS_MASK > EXP_MASK
+}
+
+/// Verdadeiro se x é +infinito ou -infinito (expoente 255, mantissa zero).
+#[inline]
+pub const fn isinff(x: f32) -> bool {
+    x.to_bits() & ABS_MASK == EXP_MASK
 }
 
 // =============================================================================
@@ -105,6 +224,45 @@ pub fn fmodf(x: f32, y: f32) -> f32 {
     x - truncf(x / y) * y
 }
 
+/// Resto da divisão por uma potência de dois positiva (x mod y).
+///
+/// Mesmo resultado de `fmodf` (sinal de x), mas calculado zerando os bits
+/// da mantissa que valem múltiplos de `y`: exato para qualquer x finito,
+/// sem divisão e sem a conversão para i32 que limita `fmodf`.
+/// `y` precisa ser uma potência de dois normal (verificado em debug).
+#[inline]
+pub fn fmod_pow2f(x: f32, y: f32) -> f32 {
+    debug_assert!(
+        y > 0.0 && y.is_normal() && y.to_bits() & 0x007fffff == 0,
+        "fmod_pow2f: y deve ser potência de dois positiva"
+    );
+
+    if !x.is_finite() {
+        return f32::NAN;
+    }
+
+    let bits = x.to_bits();
+    let e_x = ((bits >> 23) & 0xff) as i32;
+    let e_y = ((y.to_bits() >> 23) & 0xff) as i32;
+    let d = e_x - e_y;
+
+    // |x| < y: o próprio x é o resto
+    if d < 0 {
+        return x;
+    }
+
+    // Todos os bits de x valem múltiplos de y
+    if d >= 23 {
+        return copysignf(0.0, x);
+    }
+
+    // Zera os bits abaixo de y: sobra trunc(x / y) * y
+    let mask = (1u32 << (23 - d)) - 1;
+    let q = f32::from_bits(bits & !mask);
+
+    x - q
+}
+
 /// Resto euclidiano (sempre positivo).
 #[inline]
 pub fn remf(x: f32, y: f32) -> f32 {
@@ -123,6 +281,10 @@ pub fn remf(x: f32, y: f32) -> f32 {
 /// Floor para f64.
 #[inline]
 pub fn floor(x: f64) -> f64 {
+    if x.is_nan() || abs(x) >= INTEGRAL_THRESHOLD_F64 {
+        return x;
+    }
+
     let xi = x as i64;
     let xf = xi as f64;
     if x < xf {
@@ -135,6 +297,10 @@ pub fn floor(x: f64) -> f64 {
 /// Ceil para f64.
 #[inline]
 pub fn ceil(x: f64) -> f64 {
+    if x.is_nan() || abs(x) >= INTEGRAL_THRESHOLD_F64 {
+        return x;
+    }
+
     let xi = x as i64;
     let xf = xi as f64;
     if x > xf {
@@ -145,21 +311,52 @@ pub fn ceil(x: f64) -> f64 {
 }
 
 /// Round para f64.
+///
+/// Empates para longe do zero, como `roundf`.
 #[inline]
 pub fn round(x: f64) -> f64 {
-    if x >= 0.0 {
-        floor(x + 0.5)
+    let t = trunc(x);
+    let d = x - t;
+    if d >= 0.5 {
+        t + 1.0
+    } else if d <= -0.5 {
+        t - 1.0
     } else {
-        ceil(x - 0.5)
+        t
+    }
+}
+
+/// Round para f64 com empates para o par, como `roundf_ties_even`.
+#[inline]
+pub fn round_ties_even(x: f64) -> f64 {
+    let t = trunc(x);
+    let d = x - t;
+    let a = abs(d);
+
+    // |t| < 2^52 aqui (senão d == 0), então cabe em i64
+    if a > 0.5 || (a == 0.5 && (t as i64) & 1 != 0) {
+        if d > 0.0 {
+            t + 1.0
+        } else {
+            t - 1.0
+        }
+    } else {
+        t
     }
 }
 
 /// Truncate para f64.
 #[inline]
 pub fn trunc(x: f64) -> f64 {
+    if x.is_nan() || abs(x) >= INTEGRAL_THRESHOLD_F64 {
+        return x;
+    }
     x as i64 as f64
 }
 
+// A partir de 2^52 todo f64 já é inteiro
+const INTEGRAL_THRESHOLD_F64: f64 = 4503599627370496.0;
+
 /// Abs para f64.
 #[inline]
 pub fn abs(x: f64) -> f64 {
@@ -169,3 +366,204 @@ pub fn abs(x: f64) -> f64 {
         x
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Resto exato: em f64 o `%` de dois f32 é exato e cabe em f32
+    fn fmod_ref(x: f32, y: f32) -> f32 {
+        ((x as f64) % (y as f64)) as f32
+    }
+
+    #[test]
+    fn fmod_pow2_matches_fmodf_for_power_of_two_divisors() {
+        let divisors = [0.125, 0.5, 1.0, 2.0, 4.0, 64.0, 1024.0];
+        for &y in &divisors {
+            for i in -500..=500 {
+                let x = i as f32 * 0.731 + 0.0625;
+                let r = fmod_pow2f(x, y);
+                assert_eq!(r.to_bits(), fmod_ref(x, y).to_bits(), "{} mod {}", x, y);
+                assert_eq!(r, fmodf(x, y), "{} mod {}", x, y);
+            }
+        }
+        assert_eq!(fmod_pow2f(-7.25, 2.0), -1.25);
+        assert_eq!(fmod_pow2f(0.5, 4.0), 0.5);
+        assert!(fmod_pow2f(f32::INFINITY, 2.0).is_nan());
+    }
+
+    #[test]
+    fn fmod_pow2_is_exact_where_fmodf_overflows() {
+        // x / y estoura para infinito e fmodf devolve -inf
+        let y = 1.0 / 1024.0;
+        assert_eq!(fmodf(3.0e38, y), f32::NEG_INFINITY);
+        assert_eq!(fmod_pow2f(3.0e38, y), 0.0);
+
+        for &x in &[1.0e35, 123456.789, -9.87654e20, 1.5e-3] {
+            let y = 1.0 / 65536.0;
+            assert_eq!(
+                fmod_pow2f(x, y).to_bits(),
+                fmod_ref(x, y).to_bits(),
+                "{}",
+                x
+            );
+        }
+    }
+
+    #[test]
+    fn floor_ceil_huge_values_are_unchanged() {
+        for x in [1e30f32, -1e30, 2.2e9, -3.0e9, f32::MAX, f32::MIN] {
+            assert_eq!(floorf(x), x);
+            assert_eq!(ceilf(x), x);
+            assert_eq!(fractf(x), 0.0);
+        }
+        assert_eq!(floorf(f32::INFINITY), f32::INFINITY);
+        assert_eq!(ceilf(f32::NEG_INFINITY), f32::NEG_INFINITY);
+        assert!(floorf(f32::NAN).is_nan());
+    }
+
+    #[test]
+    fn floor_ceil_around_two_pow_23() {
+        // Maior f32 com parte fracionária: 8388607.5
+        assert_eq!(floorf(8388607.5), 8388607.0);
+        assert_eq!(ceilf(8388607.5), 8388608.0);
+        assert_eq!(floorf(-8388607.5), -8388608.0);
+        assert_eq!(ceilf(-8388607.5), -8388607.0);
+        for x in [8388607.0f32, 8388608.0, 8388609.0, 8388610.0] {
+            assert_eq!(floorf(x), x);
+            assert_eq!(ceilf(x), x);
+            assert_eq!(floorf(-x), -x);
+            assert_eq!(ceilf(-x), -x);
+        }
+    }
+
+    #[test]
+    fn floor_ceil_bits_match_reference() {
+        for i in -2000..=2000 {
+            let x = i as f32 * 0.37;
+            assert_eq!(floorf_bits(x), (x as f64).floor() as f32, "floor({})", x);
+            assert_eq!(ceilf_bits(x), (x as f64).ceil() as f32, "ceil({})", x);
+        }
+    }
+
+    #[test]
+    fn floor_ceil_bits_edge_values() {
+        // Enormes: já são inteiros
+        for x in [1e30f32, -1e30, 8388608.0, -8388609.0, f32::MAX, f32::MIN] {
+            assert_eq!(floorf_bits(x), x);
+            assert_eq!(ceilf_bits(x), x);
+        }
+        assert_eq!(floorf_bits(8388607.5), 8388607.0);
+        assert_eq!(ceilf_bits(-8388607.5), -8388607.0);
+
+        // Minúsculos, incluindo subnormais
+        for x in [1e-40f32, 1e-20, f32::MIN_POSITIVE, 0.999_999_9] {
+            assert_eq!(floorf_bits(x), 0.0);
+            assert_eq!(ceilf_bits(x), 1.0);
+            assert_eq!(floorf_bits(-x), -1.0);
+            assert_eq!(ceilf_bits(-x).to_bits(), (-0.0f32).to_bits());
+        }
+
+        // Negativos com parte fracionária
+        assert_eq!(floorf_bits(-2.5), -3.0);
+        assert_eq!(ceilf_bits(-2.5), -2.0);
+        assert_eq!(floorf_bits(-3.0), -3.0);
+
+        // Especiais
+        assert_eq!(floorf_bits(0.0).to_bits(), 0.0f32.to_bits());
+        assert_eq!(floorf_bits(-0.0).to_bits(), (-0.0f32).to_bits());
+        assert_eq!(ceilf_bits(f32::INFINITY), f32::INFINITY);
+        assert_eq!(floorf_bits(f32::NEG_INFINITY), f32::NEG_INFINITY);
+        assert!(floorf_bits(f32::NAN).is_nan());
+        assert!(ceilf_bits(f32::NAN).is_nan());
+    }
+
+    #[test]
+    fn copysign_handles_signed_zero() {
+        assert_eq!(copysignf(3.0, -0.0), -3.0);
+        assert_eq!(copysignf(-3.0, 0.0), 3.0);
+        assert_eq!(copysignf(3.0, -2.0), -3.0);
+        assert!(copysignf(0.0, -0.0).is_sign_negative());
+        assert!(copysignf(-0.0, 0.0).is_sign_positive());
+        assert_eq!(copysignf(f32::INFINITY, -1.0), f32::NEG_INFINITY);
+    }
+
+    #[test]
+    fn copysign_propagates_nan_sign_and_payload() {
+        let neg_nan = -f32::NAN;
+        assert!(copysignf(2.0, neg_nan).is_sign_negative());
+        assert!(copysignf(2.0, f32::NAN).is_sign_positive());
+
+        let payload = f32::from_bits(0x7fc0_1234);
+        let r = copysignf(payload, -1.0);
+        assert!(r.is_nan() && r.is_sign_negative());
+        assert_eq!(r.to_bits(), 0xffc0_1234);
+        assert_eq!(copysignf(r, 1.0).to_bits(), 0x7fc0_1234);
+
+        const C: f32 = copysignf(1.5, -0.0);
+        assert_eq!(C, -1.5);
+    }
+
+    #[test]
+    fn classification_of_special_values() {
+        let subnormal = f32::from_bits(1);
+        let max_subnormal = f32::from_bits(0x007f_ffff);
+        // (valor, finito, nan, inf)
+        let cases = [
+            (f32::INFINITY, false, false, true),
+            (f32::NEG_INFINITY, false, false, true),
+            (f32::NAN, false, true, false),
+            (-f32::NAN, false, true, false),
+            (f32::from_bits(0x7f80_0001), false, true, false),
+            (subnormal, true, false, false),
+            (-max_subnormal, true, false, false),
+            (0.0, true, false, false),
+            (-0.0, true, false, false),
+            (1.5, true, false, false),
+            (-3.0e38, true, false, false),
+            (f32::MAX, true, false, false),
+            (f32::MIN_POSITIVE, true, false, false),
+        ];
+        for (x, finite, nan, inf) in cases {
+            assert_eq!(isfinitef(x), finite, "isfinitef({:e})", x);
+            assert_eq!(isnanf(x), nan, "isnanf({:e})", x);
+            assert_eq!(isinff(x), inf, "isinff({:e})", x);
+            // Mesma resposta que os métodos do core
+            assert_eq!(isfinitef(x), x.is_finite());
+            assert_eq!(isnanf(x), x.is_nan());
+            assert_eq!(isinff(x), x.is_infinite());
+        }
+    }
+
+    #[test]
+    fn tie_rounding_agrees_across_widths() {
+        // (x, longe do zero, para o par)
+        let ties = [
+            (0.5, 1.0, 0.0),
+            (1.5, 2.0, 2.0),
+            (2.5, 3.0, 2.0),
+            (-0.5, -1.0, -0.0),
+            (-1.5, -2.0, -2.0),
+            (-2.5, -3.0, -2.0),
+        ];
+        for (x, away, even) in ties {
+            assert_eq!(roundf(x as f32), away as f32, "roundf({})", x);
+            assert_eq!(round(x), away, "round({})", x);
+            assert_eq!(
+                roundf_ties_even(x as f32),
+                even as f32,
+                "roundf_ties_even({})",
+                x
+            );
+            assert_eq!(round_ties_even(x), even, "round_ties_even({})", x);
+            // Conferido contra o core
+            assert_eq!(round(x), x.round());
+            assert_eq!(round_ties_even(x), x.round_ties_even());
+        }
+
+        // Logo abaixo de 0.5 não sobe (sem o erro de somar 0.5)
+        assert_eq!(roundf(0.49999997), 0.0);
+        assert_eq!(round(0.49999999999999994), 0.0);
+        assert_eq!(round_ties_even(0.49999999999999994), 0.0);
+    }
+}
@@ -0,0 +1,524 @@
+//! # Estatística
+//!
+//! Acumuladores e estatísticas sobre fluxos e slices, sem alocação.
+
+use crate::consts::SQRT_2;
+use crate::exp::{erff, logf, rsqrtf, sqrtf};
+use crate::util::{fmaxf, fminf, maxf};
+
+// =============================================================================
+// MIN / MAX
+// =============================================================================
+
+/// Mínimo e máximo acumulados de um fluxo, sem guardar as amostras.
+///
+/// Começa em +inf/-inf, então o primeiro valor define os dois.
+/// Amostras NaN são ignoradas.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MinMax {
+    min: f32,
+    max: f32,
+}
+
+impl MinMax {
+    /// Cria acumulador vazio.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            min: f32::INFINITY,
+            max: f32::NEG_INFINITY,
+        }
+    }
+
+    /// Adiciona uma amostra.
+    #[inline]
+    pub fn push(&mut self, x: f32) {
+        // fminf/fmaxf descartam o NaN e mantêm o valor acumulado
+        self.min = fminf(self.min, x);
+        self.max = fmaxf(self.max, x);
+    }
+
+    /// Menor valor visto (+inf se vazio).
+    #[inline]
+    pub const fn min(&self) -> f32 {
+        self.min
+    }
+
+    /// Maior valor visto (-inf se vazio).
+    #[inline]
+    pub const fn max(&self) -> f32 {
+        self.max
+    }
+
+    /// Amplitude `max - min` (0 se vazio).
+    #[inline]
+    pub fn range(&self) -> f32 {
+        if self.is_empty() {
+            0.0
+        } else {
+            self.max - self.min
+        }
+    }
+
+    /// Verdadeiro se nenhuma amostra válida foi adicionada.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.min > self.max
+    }
+
+    /// Volta ao estado vazio.
+    #[inline]
+    pub fn reset(&mut self) {
+        *self = Self::new();
+    }
+}
+
+impl Default for MinMax {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Menor valor da slice, ignorando NaN (+inf se vazia).
+#[inline]
+pub fn slice_minf(data: &[f32]) -> f32 {
+    let mut mm = MinMax::new();
+    for &x in data {
+        mm.push(x);
+    }
+    mm.min()
+}
+
+/// Maior valor da slice, ignorando NaN (-inf se vazia).
+#[inline]
+pub fn slice_maxf(data: &[f32]) -> f32 {
+    let mut mm = MinMax::new();
+    for &x in data {
+        mm.push(x);
+    }
+    mm.max()
+}
+
+// =============================================================================
+// SOMA
+// =============================================================================
+
+/// Soma simples da slice (rápida, acumula erro de arredondamento).
+#[inline]
+pub fn sumf(data: &[f32]) -> f32 {
+    let mut sum = 0.0;
+    for &x in data {
+        sum += x;
+    }
+    sum
+}
+
+/// Soma compensada da slice (Kahan).
+///
+/// Carrega o erro de arredondamento de cada adição para a próxima,
+/// então valores pequenos somados a um grande não se perdem.
+#[inline]
+pub fn sum_kahanf(data: &[f32]) -> f32 {
+    let mut sum = 0.0f32;
+    let mut comp = 0.0f32;
+    for &x in data {
+        let y = x - comp;
+        let t = sum + y;
+        // (t - sum) é o que de fato entrou; a diferença para y se perdeu
+        comp = (t - sum) - y;
+        sum = t;
+    }
+    sum
+}
+
+// =============================================================================
+// NORMALIZAÇÃO
+// =============================================================================
+
+/// Reescala a slice para [0, 1] a partir do seu mínimo e máximo.
+///
+/// Amplitude zero (todos iguais) ou slice vazia zeram os valores.
+#[inline]
+pub fn normalize_range_in_placef(data: &mut [f32]) {
+    let min = slice_minf(data);
+    let range = slice_maxf(data) - min;
+
+    if !(range > 0.0 && range.is_finite()) {
+        data.fill(0.0);
+        return;
+    }
+
+    let inv = 1.0 / range;
+    for x in data.iter_mut() {
+        *x = (*x - min) * inv;
+    }
+}
+
+/// Divide a slice pela sua norma L2, deixando-a com comprimento 1.
+///
+/// Norma zero deixa a slice inalterada.
+#[inline]
+pub fn normalize_unit_in_placef(data: &mut [f32]) {
+    let mut norm_sq = 0.0;
+    for &x in data.iter() {
+        norm_sq += x * x;
+    }
+
+    if norm_sq <= 0.0 {
+        return;
+    }
+
+    let inv = rsqrtf(norm_sq);
+
+    for x in data.iter_mut() {
+        *x *= inv;
+    }
+}
+
+// =============================================================================
+// MÉDIA / VARIÂNCIA PONDERADAS
+// =============================================================================
+
+/// Média e variância ponderadas incrementais (algoritmo de West).
+///
+/// Variante ponderada do Welford: estável numericamente e sem guardar
+/// as amostras. Pesos nulos ou negativos são ignorados.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct WeightedStats {
+    weight_sum: f32,
+    mean: f32,
+    m2: f32,
+}
+
+impl WeightedStats {
+    /// Cria acumulador vazio.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            weight_sum: 0.0,
+            mean: 0.0,
+            m2: 0.0,
+        }
+    }
+
+    /// Adiciona uma amostra com peso.
+    #[inline]
+    pub fn push(&mut self, x: f32, weight: f32) {
+        if weight <= 0.0 {
+            return;
+        }
+
+        self.weight_sum += weight;
+        let delta = x - self.mean;
+        self.mean += (weight / self.weight_sum) * delta;
+        self.m2 += weight * delta * (x - self.mean);
+    }
+
+    /// Média ponderada (0 se vazio).
+    #[inline]
+    pub const fn mean(&self) -> f32 {
+        self.mean
+    }
+
+    /// Variância ponderada populacional: Σw(x - média)² / Σw (0 se vazio).
+    ///
+    /// Com pesos todos iguais, coincide com a variância populacional comum.
+    #[inline]
+    pub fn variance(&self) -> f32 {
+        if self.weight_sum > 0.0 {
+            self.m2 / self.weight_sum
+        } else {
+            0.0
+        }
+    }
+
+    /// Soma dos pesos adicionados.
+    #[inline]
+    pub const fn total_weight(&self) -> f32 {
+        self.weight_sum
+    }
+
+    /// Volta ao estado vazio.
+    #[inline]
+    pub fn reset(&mut self) {
+        *self = Self::new();
+    }
+}
+
+/// Variância populacional de uma slice pelo algoritmo de duas passadas.
+///
+/// Calcula a média primeiro e depois soma os desvios quadráticos, com o
+/// termo de correção `(Σd)²/n` que absorve o erro da própria média. É o
+/// resultado mais preciso para um conjunto fixo, mesmo com média enorme
+/// e variância pequena; prefira [`WeightedStats`] quando os dados chegam
+/// em fluxo ou não cabem em memória. Slice vazia retorna 0.
+#[inline]
+pub fn variance_two_passf(data: &[f32]) -> f32 {
+    if data.is_empty() {
+        return 0.0;
+    }
+
+    let n = data.len() as f32;
+    let mean = sum_kahanf(data) / n;
+
+    let mut sum_sq = 0.0;
+    let mut sum_d = 0.0;
+    for &x in data {
+        let d = x - mean;
+        sum_sq += d * d;
+        sum_d += d;
+    }
+
+    maxf(0.0, (sum_sq - sum_d * sum_d / n) / n)
+}
+
+// =============================================================================
+// DISTRIBUIÇÃO NORMAL
+// =============================================================================
+
+/// Função de distribuição acumulada (CDF) da normal.
+///
+/// `0.5 * (1 + erf((x - mean) / (std * √2)))`. Com `std <= 0` vira
+/// um degrau em `mean`.
+#[inline]
+pub fn normal_cdff(x: f32, mean: f32, std: f32) -> f32 {
+    if std <= 0.0 {
+        return if x < mean { 0.0 } else { 1.0 };
+    }
+    0.5 * (1.0 + erff((x - mean) / (std * SQRT_2)))
+}
+
+/// Inversa da CDF da normal (função probit).
+///
+/// Aproximação racional de Acklam (erro relativo ~1e-9 no algoritmo,
+/// limitado na prática pela precisão de `logf`/f32). Fora de (0, 1)
+/// satura: `p <= 0` retorna `f32::MIN` e `p >= 1` retorna `f32::MAX`.
+#[inline]
+pub fn normal_inv_cdff(p: f32, mean: f32, std: f32) -> f32 {
+    if p.is_nan() {
+        return p;
+    }
+    if p <= 0.0 {
+        return f32::MIN;
+    }
+    if p >= 1.0 {
+        return f32::MAX;
+    }
+
+    mean + std * probit(p)
+}
+
+// Acklam: inversa da normal padrão
+fn probit(p: f32) -> f32 {
+    const A: [f32; 6] = [
+        -3.969683028665376e+01,
+        2.209460984245205e+02,
+        -2.759285104469687e+02,
+        1.383577518672690e+02,
+        -3.066479806614716e+01,
+        2.506628277459239e+00,
+    ];
+    const B: [f32; 5] = [
+        -5.447609879822406e+01,
+        1.615858368580409e+02,
+        -1.556989798598866e+02,
+        6.680131188771972e+01,
+        -1.328068155288572e+01,
+    ];
+    const C: [f32; 6] = [
+        -7.784894002430293e-03,
+        -3.223964580411365e-01,
+        -2.400758277161838e+00,
+        -2.549732539343734e+00,
+        4.374664141464968e+00,
+        2.938163982698783e+00,
+    ];
+    const D: [f32; 4] = [
+        7.784695709041462e-03,
+        3.224671290700398e-01,
+        2.445134137142996e+00,
+        3.754408661907416e+00,
+    ];
+    const P_LOW: f32 = 0.02425;
+
+    // Caudas: aproximação em q = sqrt(-2 ln p)
+    let tail = |q: f32| {
+        (((((C[0] * q + C[1]) * q + C[2]) * q + C[3]) * q + C[4]) * q + C[5])
+            / ((((D[0] * q + D[1]) * q + D[2]) * q + D[3]) * q + 1.0)
+    };
+
+    if p < P_LOW {
+        tail(sqrtf(-2.0 * logf(p)))
+    } else if p > 1.0 - P_LOW {
+        -tail(sqrtf(-2.0 * logf(1.0 - p)))
+    } else {
+        let q = p - 0.5;
+        let r = q * q;
+        (((((A[0] * r + A[1]) * r + A[2]) * r + A[3]) * r + A[4]) * r + A[5]) * q
+            / (((((B[0] * r + B[1]) * r + B[2]) * r + B[3]) * r + B[4]) * r + 1.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn minmax_tracks_stream() {
+        let mut mm = MinMax::new();
+        assert!(mm.is_empty());
+        assert_eq!(mm.range(), 0.0);
+
+        mm.push(3.0);
+        assert_eq!((mm.min(), mm.max()), (3.0, 3.0));
+
+        for x in [1.5, -2.0, f32::NAN, 7.25, 0.0] {
+            mm.push(x);
+        }
+        assert_eq!(mm.min(), -2.0);
+        assert_eq!(mm.max(), 7.25);
+        assert_eq!(mm.range(), 9.25);
+    }
+
+    #[test]
+    fn minmax_reset_starts_over() {
+        let mut mm = MinMax::new();
+        mm.push(-100.0);
+        mm.push(100.0);
+        mm.reset();
+        assert!(mm.is_empty());
+        assert_eq!(mm.min(), f32::INFINITY);
+        assert_eq!(mm.max(), f32::NEG_INFINITY);
+
+        mm.push(4.0);
+        mm.push(5.0);
+        assert_eq!((mm.min(), mm.max(), mm.range()), (4.0, 5.0, 1.0));
+    }
+
+    #[test]
+    fn weighted_stats_hand_computed() {
+        // x = [1, 2, 4], w = [1, 2, 1]: média 9/4, variância 4.75/4
+        let mut ws = WeightedStats::new();
+        ws.push(1.0, 1.0);
+        ws.push(2.0, 2.0);
+        ws.push(4.0, 1.0);
+        assert!((ws.mean() - 2.25).abs() < 1e-6);
+        assert!((ws.variance() - 1.1875).abs() < 1e-6);
+        assert_eq!(ws.total_weight(), 4.0);
+
+        // Peso zero ou negativo é ignorado
+        ws.push(100.0, 0.0);
+        ws.push(100.0, -1.0);
+        assert!((ws.mean() - 2.25).abs() < 1e-6);
+    }
+
+    #[test]
+    fn weighted_stats_equal_weights_reduce_to_unweighted() {
+        let data = [1.0, 2.0, 4.0, -3.5, 0.25, 8.0];
+        let n = data.len() as f32;
+        let mean = data.iter().sum::<f32>() / n;
+        let var = data.iter().map(|x| (x - mean) * (x - mean)).sum::<f32>() / n;
+
+        let mut ws = WeightedStats::new();
+        for &x in &data {
+            ws.push(x, 3.0);
+        }
+        assert!((ws.mean() - mean).abs() < 1e-5);
+        assert!((ws.variance() - var).abs() < 1e-4);
+        assert!((ws.variance() - variance_two_passf(&data)).abs() < 1e-4);
+
+        ws.reset();
+        assert_eq!((ws.mean(), ws.variance()), (0.0, 0.0));
+    }
+
+    #[test]
+    fn normal_cdf_at_mean_is_half() {
+        for &(mean, std) in &[(0.0, 1.0), (5.0, 2.0), (-3.0, 0.1)] {
+            assert!((normal_cdff(mean, mean, std) - 0.5).abs() < 1e-6);
+        }
+        // ±1σ: 0.158655 / 0.841345
+        assert!((normal_cdff(-1.0, 0.0, 1.0) - 0.158_655).abs() < 1e-5);
+        assert!((normal_cdff(3.0, 1.0, 2.0) - 0.841_345).abs() < 1e-5);
+        assert!((normal_inv_cdff(0.5, 2.0, 3.0) - 2.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn normal_cdf_and_inverse_round_trip() {
+        for i in 1..100 {
+            let p = i as f32 / 100.0;
+            let x = normal_inv_cdff(p, 1.5, 0.5);
+            assert!((normal_cdff(x, 1.5, 0.5) - p).abs() < 1e-5, "p = {}", p);
+        }
+        for &p in &[1e-4, 0.01, 0.97, 0.9999] {
+            let x = normal_inv_cdff(p, 0.0, 1.0);
+            assert!((normal_cdff(x, 0.0, 1.0) - p).abs() < 1e-5, "p = {}", p);
+        }
+        assert_eq!(normal_inv_cdff(0.0, 0.0, 1.0), f32::MIN);
+        assert_eq!(normal_inv_cdff(1.0, 0.0, 1.0), f32::MAX);
+        assert!(normal_inv_cdff(f32::NAN, 0.0, 1.0).is_nan());
+    }
+
+    #[test]
+    fn normalize_range_spans_unit_interval() {
+        let mut data = [3.0, -1.0, 7.0, 2.0, 5.0];
+        normalize_range_in_placef(&mut data);
+        assert_eq!(data, [0.5, 0.0, 1.0, 0.375, 0.75]);
+
+        let mut flat = [4.0; 3];
+        normalize_range_in_placef(&mut flat);
+        assert_eq!(flat, [0.0; 3]);
+        normalize_range_in_placef(&mut []);
+    }
+
+    #[test]
+    fn normalize_unit_has_unit_norm() {
+        let mut data = [3.0, -4.0, 12.0, 0.5, -7.25];
+        let before = data;
+        normalize_unit_in_placef(&mut data);
+        let norm_sq: f32 = data.iter().map(|x| x * x).sum();
+        assert!((norm_sq - 1.0).abs() < 1e-5);
+        // Direção preservada
+        assert!((data[1] / data[0] - before[1] / before[0]).abs() < 1e-5);
+
+        let mut zeros = [0.0; 4];
+        normalize_unit_in_placef(&mut zeros);
+        assert_eq!(zeros, [0.0; 4]);
+    }
+
+    #[test]
+    fn kahan_keeps_small_values_lost_by_naive_sum() {
+        // 1.0 seguido de um milhão de 1e-8: cada parcela some na soma simples
+        let mut data = std::vec![1e-8f32; 1_000_001];
+        data[0] = 1.0;
+
+        assert_eq!(sumf(&data), 1.0);
+        let kahan = sum_kahanf(&data);
+        assert!((kahan - 1.01).abs() < 1e-6, "kahan = {}", kahan);
+
+        assert_eq!(sumf(&[]), 0.0);
+        assert_eq!(sum_kahanf(&[1.5, 2.5, -1.0]), 3.0);
+    }
+
+    #[test]
+    fn two_pass_variance_survives_huge_mean() {
+        // Média 1e4, desvios de ±0.5: variância exata 0.125
+        let data: [f32; 5] = [9999.5, 9999.75, 10000.0, 10000.25, 10000.5];
+        let two_pass = variance_two_passf(&data);
+        assert!(
+            (two_pass - 0.125).abs() < 1e-6,
+            "duas passadas: {}",
+            two_pass
+        );
+
+        // Soma de quadrados ingênua: E[x²] - média² cancela catastroficamente
+        let n = data.len() as f32;
+        let sum: f32 = data.iter().sum();
+        let sum_sq: f32 = data.iter().map(|x| x * x).sum();
+        let naive = sum_sq / n - (sum / n) * (sum / n);
+        assert!((naive - 0.125).abs() > 0.1, "ingênua: {}", naive);
+
+        assert_eq!(variance_two_passf(&[]), 0.0);
+        assert_eq!(variance_two_passf(&[3.0; 8]), 0.0);
+    }
+}
@@ -0,0 +1,189 @@
+//! # Transformações
+//!
+//! Transformação TRS (translação, rotação, escala) para grafos de cena.
+
+use crate::approx::LOOSE_EPSILON_F32;
+use crate::mat::Mat4;
+use crate::quat::Quat;
+use crate::util::is_closef;
+use crate::vec::Vec3;
+
+// =============================================================================
+// TRANSFORM
+// =============================================================================
+
+/// Transformação TRS.
+///
+/// Aplicada a um ponto na ordem escala → rotação → translação.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Transform {
+    pub translation: Vec3,
+    pub rotation: Quat,
+    pub scale: Vec3,
+}
+
+impl Transform {
+    /// Transformação nula.
+    pub const IDENTITY: Transform =
+        Transform::new(Vec3::ZERO, Quat::identity(), Vec3::new(1.0, 1.0, 1.0));
+
+    /// Cria uma transformação.
+    #[inline]
+    pub const fn new(translation: Vec3, rotation: Quat, scale: Vec3) -> Self {
+        Self {
+            translation,
+            rotation,
+            scale,
+        }
+    }
+
+    /// Matriz equivalente (T * R * S).
+    #[inline]
+    pub fn to_mat4(&self) -> Mat4 {
+        Mat4::translate(self.translation) * Mat4::from_quat(self.rotation) * Mat4::scale(self.scale)
+    }
+
+    /// Transforma um ponto (escala, rotação e translação).
+    #[inline]
+    pub fn transform_point(&self, p: Vec3) -> Vec3 {
+        self.transform_vector(p) + self.translation
+    }
+
+    /// Transforma um vetor (escala e rotação, sem translação).
+    #[inline]
+    pub fn transform_vector(&self, v: Vec3) -> Vec3 {
+        self.rotation.rotate_vec3(mul_components(v, self.scale))
+    }
+
+    /// Transformação inversa.
+    ///
+    /// Exata para escala uniforme ou sem rotação. Com escala não uniforme
+    /// e rotação a inversa não é representável como TRS (a escala teria de
+    /// vir depois da rotação); esse caso é rejeitado em debug. Componentes
+    /// de escala nulos geram infinitos.
+    #[inline]
+    pub fn inverse(&self) -> Transform {
+        debug_assert!(
+            self.has_trs_inverse(),
+            "Transform::inverse: escala não uniforme com rotação não tem inversa TRS"
+        );
+
+        let rotation = self.rotation.conjugate();
+        let scale = Vec3::new(1.0 / self.scale.x, 1.0 / self.scale.y, 1.0 / self.scale.z);
+        let translation = mul_components(rotation.rotate_vec3(-self.translation), scale);
+
+        Transform::new(translation, rotation, scale)
+    }
+
+    // Escala uniforme ou rotação nula: R e S comutam e a inversa é TRS
+    #[inline]
+    fn has_trs_inverse(&self) -> bool {
+        let s = self.scale;
+        let uniform = is_closef(s.x, s.y, LOOSE_EPSILON_F32, 0.0)
+            && is_closef(s.x, s.z, LOOSE_EPSILON_F32, 0.0);
+        let q = self.rotation;
+        let no_rotation = q.x == 0.0 && q.y == 0.0 && q.z == 0.0;
+
+        uniform || no_rotation
+    }
+
+    /// Compõe com uma transformação filha (`self` é o pai).
+    ///
+    /// O resultado equivale a aplicar `child` e depois `self`.
+    /// Exato quando a escala do pai é uniforme.
+    #[inline]
+    pub fn combine(&self, child: &Transform) -> Transform {
+        Transform::new(
+            self.transform_point(child.translation),
+            self.rotation * child.rotation,
+            mul_components(self.scale, child.scale),
+        )
+    }
+}
+
+impl Default for Transform {
+    #[inline]
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+// Produto componente a componente
+#[inline]
+fn mul_components(a: Vec3, b: Vec3) -> Vec3 {
+    Vec3::new(a.x * b.x, a.y * b.y, a.z * b.z)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::consts::FRAC_PI_2;
+    use crate::vec::VecN;
+
+    fn assert_vec_close(a: Vec3, b: Vec3) {
+        let d = a - b;
+        assert!(d.length() < 1e-4, "{a:?} != {b:?}");
+    }
+
+    fn sample() -> Transform {
+        Transform::new(
+            Vec3::new(1.0, 2.0, 3.0),
+            Quat::from_axis_angle(Vec3::new(0.0, 0.0, 1.0), FRAC_PI_2),
+            Vec3::new(2.0, 2.0, 2.0),
+        )
+    }
+
+    #[test]
+    fn transform_point_applies_scale_rotation_translation() {
+        let t = Transform::new(
+            Vec3::new(1.0, 2.0, 3.0),
+            Quat::from_axis_angle(Vec3::new(0.0, 0.0, 1.0), FRAC_PI_2),
+            Vec3::new(2.0, 1.0, 1.0),
+        );
+
+        // (1, 0, 0) -> escala (2, 0, 0) -> gira (0, 2, 0) -> translada
+        let p = t.transform_point(Vec3::new(1.0, 0.0, 0.0));
+        assert_vec_close(p, Vec3::new(1.0, 4.0, 3.0));
+
+        // Vetores ignoram a translação
+        let v = t.transform_vector(Vec3::new(1.0, 0.0, 0.0));
+        assert_vec_close(v, Vec3::new(0.0, 2.0, 0.0));
+
+        // A matriz aplica a mesma ordem
+        let m = t.to_mat4() * VecN::new([1.0, 0.0, 0.0, 1.0]);
+        assert_vec_close(Vec3::new(m.0[0], m.0[1], m.0[2]), p);
+    }
+
+    #[test]
+    fn combine_with_inverse_is_identity() {
+        let t = sample();
+        for id in [t.combine(&t.inverse()), t.inverse().combine(&t)] {
+            assert_vec_close(id.translation, Vec3::ZERO);
+            assert_vec_close(id.scale, Vec3::new(1.0, 1.0, 1.0));
+            assert!(id.rotation.dot(Quat::identity()).abs() > 1.0 - 1e-4);
+        }
+
+        let p = Vec3::new(-3.0, 0.5, 7.0);
+        assert_vec_close(t.inverse().transform_point(t.transform_point(p)), p);
+    }
+
+    #[test]
+    fn inverse_of_non_uniform_scale_without_rotation_is_exact() {
+        let t = Transform::new(
+            Vec3::new(1.0, -2.0, 0.5),
+            Quat::identity(),
+            Vec3::new(2.0, 4.0, 0.5),
+        );
+        let p = Vec3::new(3.0, 1.0, -2.0);
+        assert_vec_close(t.inverse().transform_point(t.transform_point(p)), p);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "escala não uniforme com rotação")]
+    fn inverse_rejects_non_uniform_scale_with_rotation() {
+        let mut t = sample();
+        t.scale = Vec3::new(1.0, 2.0, 3.0);
+        let _ = t.inverse();
+    }
+}
@@ -3,8 +3,12 @@
 //! Seno, cosseno, tangente e suas inversas.
 //! Implementadas usando aproximações polinomiais otimizadas para precisão gráfica.
 
-use crate::consts::{FRAC_PI_2, PI, TAU};
-use crate::round::absf;
+use crate::consts::{
+    DEG_TO_RAD, FRAC_PI_2, FRAC_PI_2_F64, FRAC_PI_4, PI, PI_F64, RAD_TO_DEG, TAU, TAU_F64,
+};
+use crate::exp::{hypot3f, hypotf, sqrtf};
+use crate::round::{abs, absf, round, roundf};
+use crate::util::clampf;
 
 // =============================================================================
 // SIN / COS
@@ -12,19 +16,10 @@ use crate::round::absf;
 
 /// Seno (entrada em radianos).
 ///
-/// Precisão: ~6 dígitos significativos (suficiente para gráficos).
+/// Precisão: erro absoluto < 1e-6 em todo o período.
 #[inline]
 pub fn sinf(x: f32) -> f32 {
-    // Normaliza para [-π, π]
-    let x = normalize_angle(x);
-
-    // Aproximação polinomial (Bhaskara I, modificada)
-    // Mais precisa que Taylor para gráficos
-    if x >= 0.0 {
-        sin_approx(x)
-    } else {
-        -sin_approx(-x)
-    }
+    sin_poly(fold_half_pi(normalize_angle(x)))
 }
 
 /// Cosseno (entrada em radianos).
@@ -34,59 +29,147 @@ pub fn cosf(x: f32) -> f32 {
 }
 
 /// Tangente (entrada em radianos).
+///
+/// Perto dos polos (±π/2) usa a recíproca da cotangente, mantendo a
+/// precisão e o sinal correto dos dois lados do polo. Como π/2 não é
+/// representável em f32, o resíduo nunca é zero e o resultado é sempre
+/// finito.
 #[inline]
 pub fn tanf(x: f32) -> f32 {
-    let c = cosf(x);
-    if absf(c) < 1e-10 {
-        if c >= 0.0 {
-            f32::MAX
-        } else {
-            f32::MIN
-        }
+    let x = normalize_angle(x);
+
+    // Em [-π, π] os polos são ±π/2; e = x - polo mais próximo.
+    // x ∓ FRAC_PI_2 é exato e FRAC_PI_2_LO repõe o que o f32 não
+    // representa de π/2, então e tem o sinal certo dos dois lados
+    let e = if x >= 0.0 {
+        (x - FRAC_PI_2) - FRAC_PI_2_LO
     } else {
-        sinf(x) / c
+        (x + FRAC_PI_2) + FRAC_PI_2_LO
+    };
+
+    if absf(e) < TAN_POLE_WINDOW {
+        // tan(±π/2 + e) = -1 / tan(e), com tan(e) ≈ e + e³/3 + 2e⁵/15
+        let e2 = e * e;
+        let tan_e = e * (1.0 + e2 * (1.0 / 3.0 + e2 * (2.0 / 15.0)));
+        return -1.0 / tan_e;
     }
+
+    let (s, c) = sincosf(x);
+    s / c
 }
 
-// Aproximação de seno para [0, π]
-fn sin_approx(x: f32) -> f32 {
-    // Para x em [0, π], usamos uma aproximação quadrática melhorada
-    // Baseada em: sin(x) ≈ (16x(π-x)) / (5π² - 4x(π-x))
-    let x = if x > PI { x - PI } else { x };
+// Janela em torno dos polos onde tanf usa a série da cotangente
+const TAN_POLE_WINDOW: f32 = 0.1;
 
-    let num = 16.0 * x * (PI - x);
-    let den = 5.0 * PI * PI - 4.0 * x * (PI - x);
+// π/2 - FRAC_PI_2 (parte que o f32 não representa)
+const FRAC_PI_2_LO: f32 = -4.371139e-8;
 
-    let result = num / den;
+// Polinômio ímpar de grau 9 para sin em [-π/2, π/2], ajustado nos
+// nós de Chebyshev (quase minimax, erro ~3e-9 antes do arredondamento)
+const SIN_C1: f32 = 0.99999997652;
+const SIN_C3: f32 = -0.16666647597;
+const SIN_C5: f32 = 0.0083328992754;
+const SIN_C7: f32 = -0.00019800868105;
+const SIN_C9: f32 = 2.5904350121e-6;
 
-    // Corrige sinal para segunda metade
-    if x > PI {
-        -result
+// Simetria sin(π - x) = sin(x): leva [-π, π] para [-π/2, π/2]
+#[inline]
+fn fold_half_pi(x: f32) -> f32 {
+    if x > FRAC_PI_2 {
+        PI - x
+    } else if x < -FRAC_PI_2 {
+        -PI - x
     } else {
-        result
+        x
     }
 }
 
-/// Normaliza ângulo para [-π, π].
 #[inline]
-fn normalize_angle(x: f32) -> f32 {
-    let mut x = x;
+fn sin_poly(x: f32) -> f32 {
+    let x2 = x * x;
+    x * (SIN_C1 + x2 * (SIN_C3 + x2 * (SIN_C5 + x2 * (SIN_C7 + x2 * SIN_C9))))
+}
 
+/// Normaliza ângulo para [-π, π].
+///
+/// Subtrai o múltiplo de 2π mais próximo usando 2π em duas partes
+/// (Cody-Waite), então o resíduo continua preciso para ângulos grandes
+/// (|x| até ~4·10⁵ sem perda além do próprio arredondamento de x).
+/// Acima disso a redução é feita em f64: o resultado sempre cai em
+/// [-π, π], mas perde precisão gradualmente. Infinitos e NaN retornam NaN.
+#[inline]
+pub fn normalize_angle(x: f32) -> f32 {
     // Rápido para valores próximos de zero
-    if x >= -PI && x <= PI {
+    if (-PI..=PI).contains(&x) {
         return x;
     }
 
-    // Normaliza para [-2π, 2π]
-    x = x - ((x / TAU) as i32 as f32) * TAU;
+    if !x.is_finite() {
+        return f32::NAN;
+    }
+
+    let k = roundf(x / TAU);
+    if absf(k) >= TAU_HI_EXACT_K {
+        return normalize_angle_large(x);
+    }
 
-    // Normaliza para [-π, π]
-    if x > PI {
-        x - TAU
-    } else if x < -PI {
-        x + TAU
+    let r = (x - k * TAU_HI) - k * TAU_LO;
+
+    // Arredondamento perto de ±π pode deixar o resíduo um pouco fora
+    if r > PI {
+        r - TAU
+    } else if r < -PI {
+        r + TAU
     } else {
-        x
+        r
+    }
+}
+
+// 2π = TAU_HI + TAU_LO; TAU_HI tem só 8 bits de mantissa, então
+// k * TAU_HI é exato para |k| < 2^16
+const TAU_HI: f32 = 6.28125;
+const TAU_LO: f32 = 0.0019353071795862;
+const TAU_HI_EXACT_K: f32 = 65536.0;
+
+// Redução em f64 para |x| grande; repete enquanto o arredondamento de
+// k·2π deixar resíduo fora de [-π, π]
+fn normalize_angle_large(x: f32) -> f32 {
+    let mut r = x as f64;
+    while abs(r) > PI_F64 {
+        let q = r / TAU_F64;
+        // A partir de 2^52 todo f64 já é inteiro
+        let k = if abs(q) < 4503599627370496.0 {
+            round(q)
+        } else {
+            q
+        };
+        r -= k * TAU_F64;
+    }
+    r as f32
+}
+
+/// Envolve ângulo em [-π, π].
+///
+/// Mesmo que [`normalize_angle`].
+#[inline]
+pub fn wrap_to_pi(x: f32) -> f32 {
+    normalize_angle(x)
+}
+
+/// Envolve ângulo em [0, 2π).
+#[inline]
+pub fn wrap_to_2pi(x: f32) -> f32 {
+    let r = normalize_angle(x);
+    if r >= 0.0 {
+        return r;
+    }
+
+    // -ε + 2π pode arredondar para 2π
+    let r = r + TAU;
+    if r >= TAU {
+        0.0
+    } else {
+        r
     }
 }
 
@@ -100,20 +183,15 @@ fn normalize_angle(x: f32) -> f32 {
 #[inline]
 pub fn asinf(x: f32) -> f32 {
     // Clamp input
-    let x = if x < -1.0 {
-        -1.0
-    } else if x > 1.0 {
-        1.0
-    } else {
-        x
-    };
+    let x = clampf(x, -1.0, 1.0);
 
-    // Aproximação polinomial
-    let x2 = x * x;
-    let x3 = x2 * x;
+    // asin(x) = atan(x / sqrt(1 - x²))
+    let c = sqrtf(1.0 - x * x);
+    if c == 0.0 {
+        return if x > 0.0 { FRAC_PI_2 } else { -FRAC_PI_2 };
+    }
 
-    // Série de Taylor melhorada para asin
-    x + x3 * (1.0 / 6.0 + x2 * (3.0 / 40.0 + x2 * (15.0 / 336.0)))
+    atanf(x / c)
 }
 
 /// Arco cosseno (retorna radianos).
@@ -136,13 +214,16 @@ pub fn atanf(x: f32) -> f32 {
     }
 
     // Aproximação polinomial para |x| <= 1
-    // atan(x) ≈ x - x³/3 + x⁵/5 - x⁷/7 + ...
+    // Abramowitz & Stegun 4.4.49: erro máximo ~2e-8
     let x2 = x * x;
-    let x3 = x2 * x;
-    let x5 = x3 * x2;
-    let x7 = x5 * x2;
+    let p = -0.3333314528
+        + x2 * (0.1999355085
+            + x2 * (-0.1420889944
+                + x2 * (0.1065626393
+                    + x2 * (-0.0752896400
+                        + x2 * (0.0429096138 + x2 * (-0.0161657367 + x2 * 0.0028662257))))));
 
-    x - x3 / 3.0 + x5 / 5.0 - x7 / 7.0
+    x + x * x2 * p
 }
 
 /// Arco tangente de dois argumentos (retorna radianos).
@@ -172,6 +253,38 @@ pub fn atan2f(y: f32, x: f32) -> f32 {
     }
 }
 
+/// Arco tangente de dois argumentos aproximado (retorna radianos).
+///
+/// Reduz ao primeiro octante com `a = min(|x|, |y|) / max(|x|, |y|)` e usa
+/// `atan(a) ≈ π/4·a + 0.273·a(1 - a)`: uma divisão, sem recursão nem
+/// chamadas transcendentes, erro máximo ~0.0038 rad. Mesmas convenções
+/// de quadrante e eixos de `atan2f`; `(0, 0)` retorna 0.
+#[inline]
+pub fn atan2f_fast(y: f32, x: f32) -> f32 {
+    let ax = absf(x);
+    let ay = absf(y);
+    if ax == 0.0 && ay == 0.0 {
+        return 0.0;
+    }
+
+    let (lo, hi) = if ax < ay { (ax, ay) } else { (ay, ax) };
+    let a = lo / hi;
+    let mut r = FRAC_PI_4 * a + 0.273 * a * (1.0 - a);
+
+    // Desfaz a redução: octante, lado de x, sinal de y
+    if ay > ax {
+        r = FRAC_PI_2 - r;
+    }
+    if x < 0.0 {
+        r = PI - r;
+    }
+    if y < 0.0 {
+        -r
+    } else {
+        r
+    }
+}
+
 // =============================================================================
 // SINCOS (otimizado)
 // =============================================================================
@@ -181,7 +294,831 @@ pub fn atan2f(y: f32, x: f32) -> f32 {
 /// Mais eficiente que chamar sinf e cosf separadamente.
 #[inline]
 pub fn sincosf(x: f32) -> (f32, f32) {
-    let s = sinf(x);
-    let c = cosf(x);
-    (s, c)
+    // Uma única redução de faixa serve aos dois
+    let x = normalize_angle(x);
+
+    // cos(x) = sin(π/2 - |x|), já dentro de [-π/2, π/2]
+    (sin_poly(fold_half_pi(x)), sin_poly(FRAC_PI_2 - absf(x)))
+}
+
+/// Seno e cosseno de cada ângulo da slice, em uma só passada.
+///
+/// As três slices precisam ter o mesmo comprimento (entra em pânico
+/// caso contrário).
+#[inline]
+pub fn sincos_slicef(angles: &[f32], sin_out: &mut [f32], cos_out: &mut [f32]) {
+    assert_eq!(
+        angles.len(),
+        sin_out.len(),
+        "sincos_slicef: tamanhos diferentes"
+    );
+    assert_eq!(
+        angles.len(),
+        cos_out.len(),
+        "sincos_slicef: tamanhos diferentes"
+    );
+
+    for ((&a, s), c) in angles
+        .iter()
+        .zip(sin_out.iter_mut())
+        .zip(cos_out.iter_mut())
+    {
+        (*s, *c) = sincosf(a);
+    }
+}
+
+// =============================================================================
+// DIREÇÕES 2D
+// =============================================================================
+
+/// Converte ângulo em vetor direção unitário `(cos, sin)`.
+///
+/// Ângulo 0 aponta para +X e cresce em direção a +Y.
+#[inline]
+pub fn angle_to_dirf(angle: f32) -> (f32, f32) {
+    let (s, c) = sincosf(angle);
+    (c, s)
+}
+
+/// Converte vetor direção em ângulo, em [-π, π].
+///
+/// Mesma convenção de [`angle_to_dirf`]; não precisa estar normalizado.
+#[inline]
+pub fn dir_to_anglef(x: f32, y: f32) -> f32 {
+    atan2f(y, x)
+}
+
+// =============================================================================
+// DIFERENÇA ANGULAR
+// =============================================================================
+
+/// Menor diferença angular com sinal de `a` até `b`, em [-π, π].
+///
+/// Positiva quando o caminho mais curto é no sentido anti-horário.
+#[inline]
+pub fn angle_diff(a: f32, b: f32) -> f32 {
+    normalize_angle(b - a)
+}
+
+/// Interpola ângulos pelo caminho mais curto do círculo.
+///
+/// De 350° para 10° avança 20° passando por 360°. O resultado não é
+/// normalizado (pode sair de [-π, π]); use [`normalize_angle`] se preciso.
+#[inline]
+pub fn lerp_angle(a: f32, b: f32, t: f32) -> f32 {
+    a + angle_diff(a, b) * t
+}
+
+// =============================================================================
+// GRAUS
+// =============================================================================
+
+/// Seno com entrada em graus.
+#[inline]
+pub fn sinf_deg(deg: f32) -> f32 {
+    sinf(deg * DEG_TO_RAD)
+}
+
+/// Cosseno com entrada em graus.
+#[inline]
+pub fn cosf_deg(deg: f32) -> f32 {
+    cosf(deg * DEG_TO_RAD)
+}
+
+/// Tangente com entrada em graus.
+#[inline]
+pub fn tanf_deg(deg: f32) -> f32 {
+    tanf(deg * DEG_TO_RAD)
+}
+
+/// Arco seno em graus.
+#[inline]
+pub fn asinf_deg(x: f32) -> f32 {
+    asinf(x) * RAD_TO_DEG
+}
+
+/// Arco cosseno em graus.
+#[inline]
+pub fn acosf_deg(x: f32) -> f32 {
+    acosf(x) * RAD_TO_DEG
+}
+
+/// Arco tangente de y/x em graus, em [-180, 180].
+#[inline]
+pub fn atan2f_deg(y: f32, x: f32) -> f32 {
+    atan2f(y, x) * RAD_TO_DEG
+}
+
+// =============================================================================
+// UNIDADES DE ÂNGULO
+// =============================================================================
+
+/// Ângulo em radianos.
+///
+/// Com [`Degrees`], evita misturar unidades em tempo de compilação; as
+/// funções com `f32` puro continuam disponíveis para caminhos críticos.
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Default)]
+pub struct Radians(pub f32);
+
+/// Ângulo em graus.
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Default)]
+pub struct Degrees(pub f32);
+
+impl Radians {
+    /// Seno do ângulo.
+    #[inline]
+    pub fn sin(self) -> f32 {
+        sinf(self.0)
+    }
+
+    /// Cosseno do ângulo.
+    #[inline]
+    pub fn cos(self) -> f32 {
+        cosf(self.0)
+    }
+
+    /// Tangente do ângulo.
+    #[inline]
+    pub fn tan(self) -> f32 {
+        tanf(self.0)
+    }
+}
+
+impl From<Degrees> for Radians {
+    #[inline]
+    fn from(deg: Degrees) -> Radians {
+        Radians(deg.0 * DEG_TO_RAD)
+    }
+}
+
+impl From<Radians> for Degrees {
+    #[inline]
+    fn from(rad: Radians) -> Degrees {
+        Degrees(rad.0 * RAD_TO_DEG)
+    }
+}
+
+// =============================================================================
+// COORDENADAS ESFÉRICAS
+// =============================================================================
+
+/// Converte coordenadas esféricas para cartesianas.
+///
+/// Convenção física (ISO 80000-2): `theta_polar` é o ângulo a partir do
+/// eixo +Z (polo), em [0, π]; `phi_azimuth` é o ângulo no plano XY a
+/// partir do eixo +X em direção a +Y.
+#[inline]
+pub fn spherical_to_cartesianf(radius: f32, theta_polar: f32, phi_azimuth: f32) -> (f32, f32, f32) {
+    let (st, ct) = sincosf(theta_polar);
+    let (sp, cp) = sincosf(phi_azimuth);
+
+    (radius * st * cp, radius * st * sp, radius * ct)
+}
+
+/// Converte coordenadas cartesianas para esféricas `(raio, polar, azimute)`.
+///
+/// Mesma convenção de [`spherical_to_cartesianf`]. O azimute sai em
+/// [-π, π]; nos polos (x = y = 0) ele é indefinido e retorna 0.
+/// A origem retorna `(0, 0, 0)`.
+#[inline]
+pub fn cartesian_to_sphericalf(x: f32, y: f32, z: f32) -> (f32, f32, f32) {
+    let r = hypot3f(x, y, z);
+    if r == 0.0 {
+        return (0.0, 0.0, 0.0);
+    }
+
+    // θ = acos(z / r), calculado via atan2 para não perder precisão
+    // perto dos polos, onde acos é mal condicionado
+    let theta = atan2f(hypotf(x, y), z);
+    let phi = atan2f(y, x);
+
+    (r, theta, phi)
+}
+
+// =============================================================================
+// TABELA DE 256 ÂNGULOS
+// =============================================================================
+
+/// Seno por tabela com ângulo de 8 bits (256 passos = uma volta).
+///
+/// Sem interpolação: degraus de 1.4°, custo de uma leitura. Aritmética
+/// com `u8::wrapping_add`/`wrapping_sub` dá a volta de graça.
+#[inline]
+pub const fn sin256(angle: u8) -> f32 {
+    SIN256_TABLE[angle as usize]
+}
+
+/// Cosseno por tabela com ângulo de 8 bits (256 passos = uma volta).
+#[inline]
+pub const fn cos256(angle: u8) -> f32 {
+    SIN256_TABLE[angle.wrapping_add(64) as usize]
+}
+
+/// Tabela de seno para ângulos de 8 bits, gerada em tempo de compilação.
+pub const SIN256_TABLE: [f32; 256] = build_sin256();
+
+const fn build_sin256() -> [f32; 256] {
+    let mut table = [0.0; 256];
+    let mut i = 0;
+    while i < 256 {
+        // Usa as simetrias do seno para só avaliar o primeiro quadrante
+        let (k, sign) = if i <= 64 {
+            (i, 1.0)
+        } else if i <= 128 {
+            (128 - i, 1.0)
+        } else if i <= 192 {
+            (i - 128, -1.0)
+        } else {
+            (256 - i, -1.0)
+        };
+        let x = k as f64 * (TAU_F64 / 256.0);
+        table[i] = (sign * sin_taylor_f64(x)) as f32;
+        i += 1;
+    }
+    table
+}
+
+// Taylor de sin até x¹⁵ (erro < 1e-12 em [0, π/2])
+const fn sin_taylor_f64(x: f64) -> f64 {
+    let x2 = x * x;
+    let mut term = x;
+    let mut sum = x;
+    let mut n = 1;
+    while n < 8 {
+        term = -term * x2 / ((2 * n) * (2 * n + 1)) as f64;
+        sum += term;
+        n += 1;
+    }
+    sum
+}
+
+// =============================================================================
+// F64 VERSIONS
+// =============================================================================
+
+/// Seno para f64.
+///
+/// Redução de Cody-Waite por π/2 e polinômios de precisão dupla
+/// (coeficientes do fdlibm). Erro ~1e-16 para |x| < 2^20·π/2; acima
+/// disso a redução perde precisão gradualmente.
+#[inline]
+pub fn sin(x: f64) -> f64 {
+    let (r, q) = reduce_half_pi_f64(x);
+    match q {
+        0 => sin_kernel_f64(r),
+        1 => cos_kernel_f64(r),
+        2 => -sin_kernel_f64(r),
+        _ => -cos_kernel_f64(r),
+    }
+}
+
+/// Cosseno para f64.
+///
+/// Mesma redução e precisão de [`sin`].
+#[inline]
+pub fn cos(x: f64) -> f64 {
+    let (r, q) = reduce_half_pi_f64(x);
+    match q {
+        0 => cos_kernel_f64(r),
+        1 => -sin_kernel_f64(r),
+        2 => -cos_kernel_f64(r),
+        _ => sin_kernel_f64(r),
+    }
+}
+
+/// Tangente para f64.
+#[inline]
+pub fn tan(x: f64) -> f64 {
+    let (r, q) = reduce_half_pi_f64(x);
+    let s = sin_kernel_f64(r);
+    let c = cos_kernel_f64(r);
+
+    // Quadrantes ímpares: tan(r + π/2) = -cot(r)
+    if q & 1 == 0 {
+        s / c
+    } else {
+        -c / s
+    }
+}
+
+/// Arco tangente para f64, em [-π/2, π/2].
+#[inline]
+pub fn atan(x: f64) -> f64 {
+    if x.is_nan() {
+        return x;
+    }
+
+    let sign = if x < 0.0 { -1.0 } else { 1.0 };
+    let mut t = abs(x);
+
+    // atan(x) = π/2 - atan(1/x)
+    let mut offset = 0.0;
+    let mut flip = false;
+    if t > 1.0 {
+        t = 1.0 / t;
+        flip = true;
+    }
+
+    // atan(t) = π/6 + atan((t√3 - 1) / (t + √3)), deixa |t| <= tan(π/12)
+    if t > TAN_PI_12_F64 {
+        t = (t * SQRT_3_F64 - 1.0) / (t + SQRT_3_F64);
+        offset = FRAC_PI_6_F64;
+    }
+
+    let mut result = offset + atan_series_f64(t);
+    if flip {
+        result = FRAC_PI_2_F64 - result;
+    }
+
+    sign * result
+}
+
+/// Arco tangente de dois argumentos para f64, em [-π, π].
+#[inline]
+pub fn atan2(y: f64, x: f64) -> f64 {
+    if x == 0.0 {
+        if y > 0.0 {
+            return FRAC_PI_2_F64;
+        } else if y < 0.0 {
+            return -FRAC_PI_2_F64;
+        } else {
+            return 0.0;
+        }
+    }
+
+    let a = atan(y / x);
+
+    if x > 0.0 {
+        a
+    } else if y >= 0.0 {
+        a + PI_F64
+    } else {
+        a - PI_F64
+    }
+}
+
+// π/2 em três partes de 33 bits: k * PIO2_n é exato para |k| < 2^20
+const PIO2_1: f64 = 1.57079632673412561417e+00;
+const PIO2_2: f64 = 6.07710050630396597660e-11;
+const PIO2_3: f64 = 2.02226624871116645580e-21;
+
+const FRAC_2_PI_F64: f64 = 0.636619772367581343075535053490057448;
+const FRAC_PI_6_F64: f64 = 0.523598775598298873077107230546583814;
+const TAN_PI_12_F64: f64 = 0.267949192431122706472553658494127633;
+const SQRT_3_F64: f64 = 1.73205080756887729352744634150587237;
+
+// Reduz x para r em [-π/4, π/4] com x = r + k·π/2; retorna (r, k mod 4)
+#[inline]
+fn reduce_half_pi_f64(x: f64) -> (f64, u32) {
+    let k = round(x * FRAC_2_PI_F64);
+    let r = ((x - k * PIO2_1) - k * PIO2_2) - k * PIO2_3;
+    (r, (k as i64 & 3) as u32)
+}
+
+// sin em [-π/4, π/4] (fdlibm __kernel_sin)
+#[inline]
+fn sin_kernel_f64(x: f64) -> f64 {
+    const S1: f64 = -1.66666666666666324348e-01;
+    const S2: f64 = 8.33333333332248946124e-03;
+    const S3: f64 = -1.98412698298579493134e-04;
+    const S4: f64 = 2.75573137070700676789e-06;
+    const S5: f64 = -2.50507602534068634195e-08;
+    const S6: f64 = 1.58969099521155010221e-10;
+
+    let z = x * x;
+    x + x * z * (S1 + z * (S2 + z * (S3 + z * (S4 + z * (S5 + z * S6)))))
+}
+
+// cos em [-π/4, π/4] (fdlibm __kernel_cos)
+#[inline]
+fn cos_kernel_f64(x: f64) -> f64 {
+    const C1: f64 = 4.16666666666666019037e-02;
+    const C2: f64 = -1.38888888888741095749e-03;
+    const C3: f64 = 2.48015872894767294178e-05;
+    const C4: f64 = -2.75573143513906633035e-07;
+    const C5: f64 = 2.08757232129817482790e-09;
+    const C6: f64 = -1.13596475577881948265e-11;
+
+    let z = x * x;
+    1.0 - 0.5 * z + z * z * (C1 + z * (C2 + z * (C3 + z * (C4 + z * (C5 + z * C6)))))
+}
+
+// Série de atan para |t| <= tan(π/12): 14 termos deixam erro < 1e-17
+#[inline]
+fn atan_series_f64(t: f64) -> f64 {
+    let t2 = t * t;
+    let mut p = 0.0;
+    let mut k = 13;
+    loop {
+        let term = 1.0 / (2 * k + 1) as f64;
+        p = if k % 2 == 0 { term } else { -term } + t2 * p;
+        if k == 0 {
+            break;
+        }
+        k -= 1;
+    }
+    t * p
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // tan em f64 do mesmo argumento f32, como referência
+    fn tan_ref(x: f32) -> f64 {
+        (x as f64).tan()
+    }
+
+    #[test]
+    fn tan_near_half_pi_has_opposite_large_signs() {
+        // FRAC_PI_2 arredonda para cima de π/2; o f32 anterior fica abaixo
+        let above = FRAC_PI_2;
+        let below = f32::from_bits(FRAC_PI_2.to_bits() - 1);
+
+        for x in [below, -above] {
+            let t = tanf(x);
+            assert!(t > 1e6, "tanf({x}) = {t}");
+            assert!(((t as f64 - tan_ref(x)) / tan_ref(x)).abs() < 1e-4);
+        }
+        for x in [above, -below] {
+            let t = tanf(x);
+            assert!(t < -1e6, "tanf({x}) = {t}");
+            assert!(((t as f64 - tan_ref(x)) / tan_ref(x)).abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn tan_is_accurate_approaching_the_pole() {
+        for d in [1e-1f32, 1e-2, 1e-3, 1e-4, 1e-5] {
+            for x in [FRAC_PI_2 - d, FRAC_PI_2 + d, -FRAC_PI_2 + d, -FRAC_PI_2 - d] {
+                let rel = ((tanf(x) as f64 - tan_ref(x)) / tan_ref(x)).abs();
+                assert!(rel < 1e-4, "tanf({x}): erro relativo {rel}");
+            }
+        }
+    }
+
+    #[test]
+    fn spherical_round_trip() {
+        for &(r, theta, phi) in &[
+            (1.0, 0.3, 0.5),
+            (2.5, 1.2, -2.0),
+            (0.7, 2.9, 3.0),
+            (10.0, FRAC_PI_2, -FRAC_PI_2),
+        ] {
+            let (x, y, z) = spherical_to_cartesianf(r, theta, phi);
+            let (r2, t2, p2) = cartesian_to_sphericalf(x, y, z);
+            assert!((r2 - r).abs() < 1e-4 * r, "r: {} vs {}", r2, r);
+            assert!((t2 - theta).abs() < 1e-4, "theta: {} vs {}", t2, theta);
+            assert!((p2 - phi).abs() < 1e-4, "phi: {} vs {}", p2, phi);
+        }
+    }
+
+    #[test]
+    fn spherical_poles_and_origin() {
+        // Polo norte e sul estão no eixo Z; o azimute é indefinido e vira 0
+        let (x, y, z) = spherical_to_cartesianf(3.0, 0.0, 1.0);
+        assert!(x.abs() < 1e-6 && y.abs() < 1e-6 && (z - 3.0).abs() < 1e-6);
+        let (r, theta, phi) = cartesian_to_sphericalf(0.0, 0.0, 3.0);
+        assert!((r - 3.0).abs() < 1e-5);
+        assert_eq!((theta, phi), (0.0, 0.0));
+
+        let (r, theta, phi) = cartesian_to_sphericalf(0.0, 0.0, -2.0);
+        assert!((r - 2.0).abs() < 1e-5);
+        assert!((theta - PI).abs() < 1e-6);
+        assert_eq!(phi, 0.0);
+
+        assert_eq!(cartesian_to_sphericalf(0.0, 0.0, 0.0), (0.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn sin256_table_matches_sinf() {
+        for a in 0..=255u8 {
+            let angle = a as f32 * (TAU / 256.0);
+            assert!((sin256(a) - sinf(angle)).abs() < 1e-6, "sin256({})", a);
+            assert!((cos256(a) - cosf(angle)).abs() < 1e-6, "cos256({})", a);
+        }
+        assert_eq!(sin256(0), 0.0);
+        assert_eq!(sin256(64), 1.0);
+        assert_eq!(cos256(0), 1.0);
+        // A volta é de graça com aritmética de u8
+        assert_eq!(sin256(250u8.wrapping_add(10)), sin256(4));
+    }
+
+    #[test]
+    fn angle_direction_cardinals() {
+        let close = |(x, y): (f32, f32), (ex, ey): (f32, f32)| {
+            (x - ex).abs() < 1e-6 && (y - ey).abs() < 1e-6
+        };
+        assert!(close(angle_to_dirf(0.0), (1.0, 0.0)));
+        assert!(close(angle_to_dirf(FRAC_PI_2), (0.0, 1.0)));
+        assert!(close(angle_to_dirf(PI), (-1.0, 0.0)));
+        assert!(close(angle_to_dirf(-FRAC_PI_2), (0.0, -1.0)));
+
+        assert_eq!(dir_to_anglef(1.0, 0.0), 0.0);
+        assert!((dir_to_anglef(0.0, 1.0) - FRAC_PI_2).abs() < 1e-6);
+        assert!((dir_to_anglef(-1.0, 0.0) - PI).abs() < 1e-6);
+        assert!((dir_to_anglef(0.0, -1.0) + FRAC_PI_2).abs() < 1e-6);
+    }
+
+    #[test]
+    fn angle_direction_round_trip() {
+        for i in -31..=31 {
+            let angle = i as f32 * 0.1;
+            let (x, y) = angle_to_dirf(angle);
+            assert!((x * x + y * y - 1.0).abs() < 1e-5);
+            assert!(
+                (dir_to_anglef(x, y) - angle).abs() < 1e-5,
+                "ângulo {}",
+                angle
+            );
+            // Comprimento não importa
+            assert!((dir_to_anglef(7.0 * x, 7.0 * y) - angle).abs() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn sinf_full_period_sweep() {
+        let mut worst = 0.0f64;
+        let steps = 100_000;
+        for i in 0..=steps {
+            let x = i as f32 * (TAU / steps as f32);
+            let err = (sinf(x) as f64 - (x as f64).sin()).abs();
+            worst = worst.max(err);
+        }
+        assert!(worst < 1e-5, "erro absoluto máximo {}", worst);
+        // Picos e zeros dos quadrantes
+        assert!((sinf(FRAC_PI_2) - 1.0).abs() < 1e-6);
+        assert!((sinf(3.0 * FRAC_PI_2) + 1.0).abs() < 1e-6);
+        assert!(sinf(PI).abs() < 1e-6);
+    }
+
+    #[test]
+    fn degree_trig_known_values() {
+        assert!((sinf_deg(30.0) - 0.5).abs() < 1e-4);
+        assert!(cosf_deg(90.0).abs() < 1e-4);
+        assert!((tanf_deg(45.0) - 1.0).abs() < 1e-4);
+        assert!((cosf_deg(-180.0) + 1.0).abs() < 1e-4);
+        assert!((asinf_deg(0.5) - 30.0).abs() < 1e-3);
+        assert!((acosf_deg(0.0) - 90.0).abs() < 1e-3);
+        assert!((atan2f_deg(1.0, -1.0) - 135.0).abs() < 1e-3);
+        assert!((atan2f_deg(-1.0, 0.0) + 90.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn degrees_radians_newtypes() {
+        let r: Radians = Degrees(180.0).into();
+        assert!((r.0 - PI).abs() < 1e-6);
+        assert!(r.sin().abs() < 1e-6);
+        assert!((r.cos() + 1.0).abs() < 1e-6);
+
+        let r = Radians::from(Degrees(30.0));
+        assert!((r.sin() - 0.5).abs() < 1e-6);
+        assert!((Radians::from(Degrees(45.0)).tan() - 1.0).abs() < 1e-5);
+
+        let d = Degrees::from(Radians(FRAC_PI_2));
+        assert!((d.0 - 90.0).abs() < 1e-4);
+        // Ida e volta
+        let back: Degrees = Radians::from(Degrees(-123.4)).into();
+        assert!((back.0 + 123.4).abs() < 1e-4);
+    }
+
+    #[test]
+    fn sincos_slice_matches_sincosf() {
+        let angles: [f32; 257] = core::array::from_fn(|i| (i as f32 - 128.0) * 0.173);
+        let mut s = [0.0; 257];
+        let mut c = [0.0; 257];
+        sincos_slicef(&angles, &mut s, &mut c);
+
+        for i in 0..angles.len() {
+            let (es, ec) = sincosf(angles[i]);
+            assert_eq!(s[i].to_bits(), es.to_bits(), "sin[{}]", i);
+            assert_eq!(c[i].to_bits(), ec.to_bits(), "cos[{}]", i);
+            let x = angles[i] as f64;
+            assert!((s[i] as f64 - x.sin()).abs() < 1e-5);
+            assert!((c[i] as f64 - x.cos()).abs() < 1e-5);
+        }
+
+        sincos_slicef(&[], &mut [], &mut []);
+    }
+
+    #[test]
+    #[should_panic(expected = "tamanhos diferentes")]
+    fn sincos_slice_rejects_mismatched_lengths() {
+        sincos_slicef(&[0.0; 4], &mut [0.0; 4], &mut [0.0; 3]);
+    }
+
+    #[test]
+    fn f64_trig_matches_reference() {
+        let angles = [
+            0.0,
+            PI_F64 / 6.0,
+            FRAC_PI_4 as f64,
+            1.0,
+            FRAC_PI_2_F64 - 1e-3,
+            2.0,
+            PI_F64,
+            -2.5,
+            TAU_F64 - 0.1,
+            100.0,
+            -12345.678,
+        ];
+        for x in angles {
+            assert!((sin(x) - x.sin()).abs() < 1e-12, "sin({})", x);
+            assert!((cos(x) - x.cos()).abs() < 1e-12, "cos({})", x);
+            let t = x.tan();
+            assert!((tan(x) - t).abs() < 1e-12 * t.abs().max(1.0), "tan({})", x);
+        }
+        assert_eq!(sin(0.0), 0.0);
+        assert_eq!(cos(0.0), 1.0);
+        assert!((sin(PI_F64 / 6.0) - 0.5).abs() < 1e-15);
+    }
+
+    #[test]
+    fn f64_atan2_quadrants() {
+        let cases = [
+            (1.0, 1.0),
+            (1.0, -1.0),
+            (-1.0, -1.0),
+            (-1.0, 1.0),
+            (3.0, 0.5),
+            (-0.2, -7.0),
+        ];
+        for (y, x) in cases {
+            let reference = f64::atan2(y, x);
+            assert!(
+                (atan2(y, x) - reference).abs() < 1e-12,
+                "atan2({}, {})",
+                y,
+                x
+            );
+        }
+        assert!((atan2(1.0, -1.0) - 0.75 * PI_F64).abs() < 1e-12);
+        assert!((atan2(-1.0, -1.0) + 0.75 * PI_F64).abs() < 1e-12);
+        assert_eq!(atan2(1.0, 0.0), FRAC_PI_2_F64);
+        assert_eq!(atan2(-1.0, 0.0), -FRAC_PI_2_F64);
+        assert_eq!(atan2(0.0, 0.0), 0.0);
+        assert!((atan2(0.0, -1.0) - PI_F64).abs() < 1e-15);
+    }
+
+    #[test]
+    fn angle_diff_takes_shortest_signed_path() {
+        let deg = |d: f32| d * DEG_TO_RAD;
+        assert!((angle_diff(deg(350.0), deg(10.0)) - deg(20.0)).abs() < 1e-5);
+        assert!((angle_diff(deg(10.0), deg(350.0)) + deg(20.0)).abs() < 1e-5);
+        assert!((angle_diff(0.0, 1.0) - 1.0).abs() < 1e-6);
+        assert!((angle_diff(1.0, 0.0) + 1.0).abs() < 1e-6);
+        // Voltas inteiras não contam
+        assert!((angle_diff(0.5, 0.7 + 3.0 * TAU) - 0.2).abs() < 1e-4);
+        for i in -50..50 {
+            let d = angle_diff(i as f32 * 0.9, i as f32 * -1.7);
+            assert!((-PI..=PI).contains(&d));
+        }
+    }
+
+    #[test]
+    fn lerp_angle_wraps_forward() {
+        let deg = |d: f32| d * DEG_TO_RAD;
+        let a = deg(350.0);
+        let b = deg(10.0);
+        // Metade do caminho é 360°, não 180°
+        let mid = lerp_angle(a, b, 0.5);
+        assert!((mid - deg(360.0)).abs() < 1e-5);
+        assert!((wrap_to_2pi(lerp_angle(a, b, 0.75)) - deg(5.0)).abs() < 1e-4);
+        assert_eq!(lerp_angle(a, b, 0.0), a);
+        assert!((normalize_angle(lerp_angle(a, b, 1.0)) - b).abs() < 1e-5);
+        // No sentido contrário também
+        assert!((lerp_angle(b, a, 0.5) - 0.0).abs() < 1e-5);
+    }
+
+    // Resto exato em f64 do mesmo argumento f32, levado para [-π, π]
+    fn wrap_ref(x: f32) -> f64 {
+        let r = (x as f64).rem_euclid(TAU_F64);
+        if r > PI_F64 {
+            r - TAU_F64
+        } else {
+            r
+        }
+    }
+
+    #[test]
+    fn wrap_to_pi_large_angles() {
+        for x in [
+            1000.0 * PI,
+            -1000.0 * PI,
+            12345.678,
+            -98765.43,
+            3.0e5,
+            -4.0e5,
+        ] {
+            let w = wrap_to_pi(x);
+            assert!((-PI..=PI).contains(&w), "{} -> {}", x, w);
+            assert!((w as f64 - wrap_ref(x)).abs() < 1e-4, "{} -> {}", x, w);
+        }
+        // 1000π em f32 é quase um múltiplo de 2π
+        assert!(wrap_to_pi(1000.0 * PI).abs() < 1e-3);
+        // Muito além da redução exata: só garante a faixa
+        for x in [1.0e7f32, -3.3e9, 1.0e20, -1.0e30, f32::MAX] {
+            let w = wrap_to_pi(x);
+            assert!((-PI..=PI).contains(&w), "{} -> {}", x, w);
+        }
+        assert!(wrap_to_pi(f32::INFINITY).is_nan());
+    }
+
+    #[test]
+    fn wrap_to_2pi_large_angles() {
+        for x in [
+            1000.0 * PI + 0.5,
+            -1000.0 * PI - 0.5,
+            -1e-7,
+            -123456.7,
+            2.0e5,
+            1.0e25,
+        ] {
+            let w = wrap_to_2pi(x);
+            assert!((0.0..TAU).contains(&w), "{} -> {}", x, w);
+        }
+        assert!((wrap_to_2pi(-FRAC_PI_2) - 3.0 * FRAC_PI_2).abs() < 1e-6);
+        assert!(
+            (wrap_to_2pi(-1000.0 * PI - 0.5) as f64
+                - wrap_ref(-1000.0 * PI - 0.5).rem_euclid(TAU_F64))
+            .abs()
+                < 1e-4
+        );
+        assert_eq!(wrap_to_2pi(0.0), 0.0);
+    }
+
+    #[test]
+    fn tan_sign_on_both_sides_of_three_half_pi() {
+        let pole = 3.0 * FRAC_PI_2;
+        for d in [1e-2f32, 1e-3, 1e-4] {
+            // À esquerda do polo tan → +∞, à direita → -∞
+            let left = tanf(pole - d);
+            let right = tanf(pole + d);
+            assert!(
+                left > 0.0 && tan_ref(pole - d) > 0.0,
+                "tanf(3π/2 - {d}) = {left}"
+            );
+            assert!(
+                right < 0.0 && tan_ref(pole + d) < 0.0,
+                "tanf(3π/2 + {d}) = {right}"
+            );
+            assert!(((left as f64 - tan_ref(pole - d)) / tan_ref(pole - d)).abs() < 1e-3);
+            assert!(((right as f64 - tan_ref(pole + d)) / tan_ref(pole + d)).abs() < 1e-3);
+        }
+        // Espelhado em -3π/2
+        assert!(tanf(-pole + 1e-3) < 0.0);
+        assert!(tanf(-pole - 1e-3) > 0.0);
+    }
+
+    #[test]
+    fn atan2f_fast_tracks_atan2f_around_the_circle() {
+        let mut max_err = 0.0f32;
+        for i in 0..3600 {
+            let a = i as f32 * (TAU / 3600.0);
+            let (s, c) = sincosf(a);
+            for r in [1e-3f32, 1.0, 250.0] {
+                let (y, x) = (s * r, c * r);
+                let mut d = absf(atan2f_fast(y, x) - atan2f(y, x));
+                // ±π são o mesmo ângulo
+                if d > PI {
+                    d = TAU - d;
+                }
+                max_err = max_err.max(d);
+            }
+        }
+        // Erro documentado: ~0.0038 rad
+        assert!(max_err < 0.005, "erro máximo {}", max_err);
+    }
+
+    #[test]
+    fn atan2f_fast_axes_match_atan2f() {
+        for (y, x) in [
+            (0.0, 1.0),
+            (1.0, 0.0),
+            (0.0, -1.0),
+            (-1.0, 0.0),
+            (3.0, 0.0),
+            (0.0, -0.5),
+        ] {
+            assert!(
+                absf(atan2f_fast(y, x) - atan2f(y, x)) < 1e-6,
+                "({}, {})",
+                y,
+                x
+            );
+        }
+        assert_eq!(atan2f_fast(0.0, 0.0), 0.0);
+    }
+
+    #[test]
+    fn tan_is_finite_at_every_float_near_the_poles() {
+        for pole in [FRAC_PI_2, -FRAC_PI_2] {
+            let bits = pole.to_bits();
+            for k in 0..64 {
+                for x in [f32::from_bits(bits - k), f32::from_bits(bits + k)] {
+                    assert!(tanf(x).is_finite(), "tanf({:e}) = {}", x, tanf(x));
+                }
+            }
+        }
+    }
 }
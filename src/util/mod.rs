@@ -2,13 +2,22 @@
 //!
 //! Funções auxiliares comuns.
 
+use crate::approx::{ApproxEq, DEFAULT_EPSILON_F32};
+use crate::consts::FRAC_PI_2;
+use crate::exp::{powf, sqrtf};
+use crate::round::{absf, ceil, fractf, isnanf, remf};
+use crate::trig::sincosf;
+
 // =============================================================================
 // MIN / MAX / CLAMP
 // =============================================================================
 
 /// Mínimo entre dois valores.
+///
+/// Comparação simples: com NaN o resultado depende da ordem dos
+/// argumentos (retorna `b`). Use [`fminf`] se houver NaN na entrada.
 #[inline]
-pub fn minf(a: f32, b: f32) -> f32 {
+pub const fn minf(a: f32, b: f32) -> f32 {
     if a < b {
         a
     } else {
@@ -17,8 +26,11 @@ pub fn minf(a: f32, b: f32) -> f32 {
 }
 
 /// Máximo entre dois valores.
+///
+/// Comparação simples: com NaN o resultado depende da ordem dos
+/// argumentos (retorna `b`). Use [`fmaxf`] se houver NaN na entrada.
 #[inline]
-pub fn maxf(a: f32, b: f32) -> f32 {
+pub const fn maxf(a: f32, b: f32) -> f32 {
     if a > b {
         a
     } else {
@@ -26,9 +38,63 @@ pub fn maxf(a: f32, b: f32) -> f32 {
     }
 }
 
+/// Mínimo com semântica IEEE-754 para NaN.
+///
+/// Se só um argumento for NaN, retorna o outro; se ambos forem, NaN.
+#[inline]
+pub const fn fminf(a: f32, b: f32) -> f32 {
+    if a.is_nan() {
+        b
+    } else if b.is_nan() {
+        a
+    } else {
+        minf(a, b)
+    }
+}
+
+/// Máximo com semântica IEEE-754 para NaN.
+///
+/// Se só um argumento for NaN, retorna o outro; se ambos forem, NaN.
+#[inline]
+pub const fn fmaxf(a: f32, b: f32) -> f32 {
+    if a.is_nan() {
+        b
+    } else if b.is_nan() {
+        a
+    } else {
+        maxf(a, b)
+    }
+}
+
+/// Mínimo entre três valores.
+#[inline]
+pub const fn min3f(a: f32, b: f32, c: f32) -> f32 {
+    minf(minf(a, b), c)
+}
+
+/// Máximo entre três valores.
+#[inline]
+pub const fn max3f(a: f32, b: f32, c: f32) -> f32 {
+    maxf(maxf(a, b), c)
+}
+
+/// Mínimo entre quatro valores.
+#[inline]
+pub const fn min4f(a: f32, b: f32, c: f32, d: f32) -> f32 {
+    minf(minf(a, b), minf(c, d))
+}
+
+/// Máximo entre quatro valores.
+#[inline]
+pub const fn max4f(a: f32, b: f32, c: f32, d: f32) -> f32 {
+    maxf(maxf(a, b), maxf(c, d))
+}
+
 /// Clamp de valor entre min e max.
+///
+/// NaN passa direto (retorna NaN); veja `clampf_nan`.
 #[inline]
-pub fn clampf(x: f32, min: f32, max: f32) -> f32 {
+pub const fn clampf(x: f32, min: f32, max: f32) -> f32 {
     if x < min {
         min
     } else if x > max {
@@ -38,12 +104,44 @@ pub fn clampf(x: f32, min: f32, max: f32) -> f32 {
     }
 }
 
+/// Clamp que substitui NaN por `fallback`.
+///
+/// Para entradas normais, igual a `clampf`.
+#[inline]
+pub const fn clampf_nan(x: f32, min: f32, max: f32, fallback: f32) -> f32 {
+    if isnanf(x) {
+        fallback
+    } else {
+        clampf(x, min, max)
+    }
+}
+
+/// Clamp que também informa se o valor estava fora da faixa.
+///
+/// Retorna `(valor_limitado, houve_clamp)`. NaN passa direto, sem clamp.
+#[inline]
+pub const fn clamp_reportf(x: f32, min: f32, max: f32) -> (f32, bool) {
+    if x < min {
+        (min, true)
+    } else if x > max {
+        (max, true)
+    } else {
+        (x, false)
+    }
+}
+
 /// Clamp de valor entre 0 e 1 (saturate).
 #[inline]
-pub fn saturatef(x: f32) -> f32 {
+pub const fn saturatef(x: f32) -> f32 {
     clampf(x, 0.0, 1.0)
 }
 
+/// Quadrado (x²).
+#[inline]
+pub const fn sqf(x: f32) -> f32 {
+    x * x
+}
+
 // =============================================================================
 // LERP / SMOOTHSTEP
 // =============================================================================
@@ -75,6 +173,67 @@ pub fn remapf(x: f32, in_min: f32, in_max: f32, out_min: f32, out_max: f32) -> f
     lerpf(out_min, out_max, t)
 }
 
+/// Remapeia valor de um range para outro aplicando uma curva de easing.
+///
+/// O t normalizado é limitado a [0, 1] antes de passar por `ease`, então
+/// a saída fica entre `out_min` e `out_max` (salvo easings com overshoot).
+#[inline]
+pub fn remap_easedf(
+    x: f32,
+    in_min: f32,
+    in_max: f32,
+    out_min: f32,
+    out_max: f32,
+    ease: fn(f32) -> f32,
+) -> f32 {
+    let t = saturatef(inv_lerpf(in_min, in_max, x));
+    lerpf(out_min, out_max, ease(t))
+}
+
+/// Tratamento de bordas para coordenadas fora de [0, 1].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EdgeMode {
+    /// Limita às bordas.
+    Clamp,
+    /// Repete (como textura em modo repeat).
+    Wrap,
+    /// Espelha a cada repetição (ida e volta).
+    Mirror,
+}
+
+/// Envolve t em [0, 1) (repetição).
+#[inline]
+pub fn wrap01f(t: f32) -> f32 {
+    fractf(t)
+}
+
+/// Espelha t em [0, 1]: sobe de 0 a 1 e volta a cada período de 2.
+#[inline]
+pub fn mirror01f(t: f32) -> f32 {
+    1.0 - absf(fractf(t * 0.5) * 2.0 - 1.0)
+}
+
+/// Remapeia valor de um range para outro com tratamento de bordas.
+///
+/// O modo é aplicado ao t normalizado antes do mapeamento para a saída.
+#[inline]
+pub fn remap_with_edgef(
+    x: f32,
+    in_min: f32,
+    in_max: f32,
+    out_min: f32,
+    out_max: f32,
+    mode: EdgeMode,
+) -> f32 {
+    let t = inv_lerpf(in_min, in_max, x);
+    let t = match mode {
+        EdgeMode::Clamp => saturatef(t),
+        EdgeMode::Wrap => wrap01f(t),
+        EdgeMode::Mirror => mirror01f(t),
+    };
+    lerpf(out_min, out_max, t)
+}
+
 /// Smoothstep hermite interpolation.
 ///
 /// Retorna 0 se x < edge0, 1 se x > edge1, e interpolação suave entre.
@@ -91,6 +250,91 @@ pub fn smootherstepf(edge0: f32, edge1: f32, x: f32) -> f32 {
     t * t * t * (t * (t * 6.0 - 15.0) + 10.0)
 }
 
+/// Derivada de `smoothstepf` em relação a x: `6t(1 - t) / (edge1 - edge0)`.
+///
+/// Zero fora de [edge0, edge1] (onde smoothstep é constante) e quando
+/// `edge0 == edge1`. O pico fica no ponto médio.
+#[inline]
+pub fn smoothstep_derivf(edge0: f32, edge1: f32, x: f32) -> f32 {
+    let width = edge1 - edge0;
+    if width == 0.0 {
+        return 0.0;
+    }
+
+    let t = clampf((x - edge0) / width, 0.0, 1.0);
+    6.0 * t * (1.0 - t) / width
+}
+
+/// Derivada de `smootherstepf` em relação a x: `30t²(1 - t)² / (edge1 - edge0)`.
+///
+/// Mesmas convenções de [`smoothstep_derivf`].
+#[inline]
+pub fn smootherstep_derivf(edge0: f32, edge1: f32, x: f32) -> f32 {
+    let width = edge1 - edge0;
+    if width == 0.0 {
+        return 0.0;
+    }
+
+    let t = clampf((x - edge0) / width, 0.0, 1.0);
+    let tu = t * (1.0 - t);
+    30.0 * tu * tu / width
+}
+
+/// Bump suave: 1 em `center`, caindo a 0 a uma distância `|edge - center|`
+/// para qualquer lado.
+///
+/// `1 - smoothstepf(0, |edge - center|, |x - center|)`, simétrico em torno
+/// do centro e 0 além da borda. Com `edge == center` retorna 1 só no
+/// próprio centro.
+#[inline]
+pub fn smooth_fallofff(center: f32, edge: f32, x: f32) -> f32 {
+    let width = absf(edge - center);
+    let d = absf(x - center);
+    if width == 0.0 {
+        return if d == 0.0 { 1.0 } else { 0.0 };
+    }
+
+    clampf(1.0 - smoothstepf(0.0, width, d), 0.0, 1.0)
+}
+
+// =============================================================================
+// WRAP / PING-PONG
+// =============================================================================
+
+/// Envolve x em [min, max), como textura em modo repeat.
+///
+/// Entradas negativas também dão a volta (`wrapf(-10, 0, 360) == 350`).
+/// Com `min == max` retorna `min`.
+#[inline]
+pub fn wrapf(x: f32, min: f32, max: f32) -> f32 {
+    let range = max - min;
+    if range == 0.0 {
+        return min;
+    }
+
+    let r = min + remf(x - min, range);
+
+    // Arredondamento de um resto negativo minúsculo pode cair em `max`
+    if r >= max {
+        min
+    } else {
+        r
+    }
+}
+
+/// Vai e volta entre 0 e `length` (onda triangular de período 2·length).
+///
+/// `length <= 0` retorna 0.
+#[inline]
+pub fn pingpongf(x: f32, length: f32) -> f32 {
+    if length <= 0.0 {
+        return 0.0;
+    }
+
+    let t = remf(x, 2.0 * length);
+    length - absf(t - length)
+}
+
 // =============================================================================
 // STEP / MIX
 // =============================================================================
@@ -113,6 +357,205 @@ pub fn mixf(a: f32, b: f32, t: f32) -> f32 {
     lerpf(a, b, t)
 }
 
+/// Seleção sem desvio: retorna `a` se `cond` for verdadeiro, senão `b`.
+///
+/// Combina os bits por máscara, como o `select` dos shaders.
+#[inline]
+pub const fn selectf(a: f32, b: f32, cond: bool) -> f32 {
+    let mask = (cond as u32).wrapping_neg();
+    f32::from_bits((a.to_bits() & mask) | (b.to_bits() & !mask))
+}
+
+// =============================================================================
+// CROSSFADE
+// =============================================================================
+
+/// Crossfade com peso suavizado por smoothstep.
+///
+/// Derivada zero nas pontas: a transição começa e termina sem tranco.
+#[inline]
+pub fn crossfade_smoothf(a: f32, b: f32, t: f32) -> f32 {
+    lerpf(a, b, smoothstepf(0.0, 1.0, t))
+}
+
+/// Crossfade de potência constante (áudio).
+///
+/// Pesos cos(t·π/2) para `a` e sin(t·π/2) para `b`: a soma dos
+/// quadrados dos pesos é 1, mantendo a energia de sinais descorrelacionados.
+#[inline]
+pub fn crossfade_equal_powerf(a: f32, b: f32, t: f32) -> f32 {
+    let (wb, wa) = sincosf(saturatef(t) * FRAC_PI_2);
+    a * wa + b * wb
+}
+
+// =============================================================================
+// sRGB
+// =============================================================================
+
+/// Converte um canal sRGB (codificado) para linear.
+///
+/// Curva sRGB exata por partes: trecho linear abaixo de 0.04045 e
+/// `((c + 0.055) / 1.055)^2.4` acima. A entrada é limitada a [0, 1].
+#[inline]
+pub fn srgb_to_linearf(c: f32) -> f32 {
+    let c = saturatef(c);
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        powf((c + 0.055) / 1.055, 2.4)
+    }
+}
+
+/// Converte um canal linear para sRGB (codificado).
+///
+/// Inversa de `srgb_to_linearf`: trecho linear `12.92 · c` abaixo de
+/// 0.0031308 e `1.055 · c^(1/2.4) - 0.055` acima. A entrada é limitada a
+/// [0, 1]; 0.5 linear vira ~0.735.
+#[inline]
+pub fn linear_to_srgbf(c: f32) -> f32 {
+    let c = saturatef(c);
+    if c <= 0.0031308 {
+        c * 12.92
+    } else {
+        1.055 * powf(c, 1.0 / 2.4) - 0.055
+    }
+}
+
+// =============================================================================
+// SEQUÊNCIAS
+// =============================================================================
+
+/// Iterador de `count` valores igualmente espaçados de `start` a `end`
+/// (inclusive nas duas pontas).
+///
+/// Cada valor é calculado por `lerpf` a partir do índice (sem acúmulo
+/// de erro) e as pontas saem exatas. `count == 1` gera só `start`.
+#[inline]
+pub fn linspacef(start: f32, end: f32, count: usize) -> Linspace {
+    Linspace {
+        start,
+        end,
+        count,
+        index: 0,
+    }
+}
+
+/// Iterador retornado por [`linspacef`].
+#[derive(Clone, Debug)]
+pub struct Linspace {
+    start: f32,
+    end: f32,
+    count: usize,
+    index: usize,
+}
+
+impl Iterator for Linspace {
+    type Item = f32;
+
+    #[inline]
+    fn next(&mut self) -> Option<f32> {
+        if self.index >= self.count {
+            return None;
+        }
+
+        let i = self.index;
+        self.index += 1;
+
+        let value = if i == 0 {
+            self.start
+        } else if i == self.count - 1 {
+            self.end
+        } else {
+            lerpf(self.start, self.end, i as f32 / (self.count - 1) as f32)
+        };
+
+        Some(value)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = self.count - self.index;
+        (n, Some(n))
+    }
+}
+
+impl ExactSizeIterator for Linspace {}
+
+/// Iterador de `start`, `start + step`, ... enquanto não alcançar `end`
+/// (exclusivo).
+///
+/// Aceita passo negativo (decrescente). Passo zero ou na direção
+/// contrária a `end` gera uma sequência vazia. A quantidade de valores
+/// é calculada na criação, `ceil((end - start) / step)` limitada a
+/// `usize::MAX`, e cada valor é `start + i * step` em f64 (sem acúmulo
+/// de erro e sem estagnar acima de 2^24 passos).
+#[inline]
+pub fn arangef(start: f32, end: f32, step: f32) -> Arange {
+    let mut arange = Arange {
+        start,
+        step,
+        count: 0,
+        index: 0,
+    };
+
+    let n = ceil((end as f64 - start as f64) / step as f64);
+    if step != 0.0 && n > 0.0 {
+        // Conversão satura; NaN já foi descartado pela comparação
+        arange.count = n as usize;
+
+        // O último valor pode arredondar para `end` em f32
+        while arange.count > 0 {
+            let last = arange.value(arange.count - 1);
+            let inside = if step > 0.0 { last < end } else { last > end };
+            if inside {
+                break;
+            }
+            arange.count -= 1;
+        }
+    }
+
+    arange
+}
+
+/// Iterador retornado por [`arangef`].
+#[derive(Clone, Debug)]
+pub struct Arange {
+    start: f32,
+    step: f32,
+    count: usize,
+    index: usize,
+}
+
+impl Arange {
+    #[inline]
+    fn value(&self, i: usize) -> f32 {
+        (self.start as f64 + i as f64 * self.step as f64) as f32
+    }
+}
+
+impl Iterator for Arange {
+    type Item = f32;
+
+    #[inline]
+    fn next(&mut self) -> Option<f32> {
+        if self.index >= self.count {
+            return None;
+        }
+
+        let value = self.value(self.index);
+        self.index += 1;
+        Some(value)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = self.count - self.index;
+        (n, Some(n))
+    }
+}
+
+impl ExactSizeIterator for Arange {}
+
 // =============================================================================
 // INTEGER VERSIONS
 // =============================================================================
@@ -137,6 +580,30 @@ pub const fn maxi(a: i32, b: i32) -> i32 {
     }
 }
 
+/// Mínimo entre três inteiros.
+#[inline]
+pub const fn min3i(a: i32, b: i32, c: i32) -> i32 {
+    mini(mini(a, b), c)
+}
+
+/// Máximo entre três inteiros.
+#[inline]
+pub const fn max3i(a: i32, b: i32, c: i32) -> i32 {
+    maxi(maxi(a, b), c)
+}
+
+/// Mínimo entre quatro inteiros.
+#[inline]
+pub const fn min4i(a: i32, b: i32, c: i32, d: i32) -> i32 {
+    mini(mini(a, b), mini(c, d))
+}
+
+/// Máximo entre quatro inteiros.
+#[inline]
+pub const fn max4i(a: i32, b: i32, c: i32, d: i32) -> i32 {
+    maxi(maxi(a, b), maxi(c, d))
+}
+
 /// Clamp de inteiro.
 #[inline]
 pub const fn clampi(x: i32, min: i32, max: i32) -> i32 {
@@ -169,6 +636,30 @@ pub const fn maxu(a: u32, b: u32) -> u32 {
     }
 }
 
+/// Mínimo entre três u32.
+#[inline]
+pub const fn min3u(a: u32, b: u32, c: u32) -> u32 {
+    minu(minu(a, b), c)
+}
+
+/// Máximo entre três u32.
+#[inline]
+pub const fn max3u(a: u32, b: u32, c: u32) -> u32 {
+    maxu(maxu(a, b), c)
+}
+
+/// Mínimo entre quatro u32.
+#[inline]
+pub const fn min4u(a: u32, b: u32, c: u32, d: u32) -> u32 {
+    minu(minu(a, b), minu(c, d))
+}
+
+/// Máximo entre quatro u32.
+#[inline]
+pub const fn max4u(a: u32, b: u32, c: u32, d: u32) -> u32 {
+    maxu(maxu(a, b), maxu(c, d))
+}
+
 /// Clamp de u32.
 #[inline]
 pub const fn clampu(x: u32, min: u32, max: u32) -> u32 {
@@ -181,6 +672,217 @@ pub const fn clampu(x: u32, min: u32, max: u32) -> u32 {
     }
 }
 
+/// Envolve inteiro no intervalo [min, max) (módulo euclidiano).
+///
+/// Ex.: `wrap_i32(-1, 0, 5) == 4`. Se `max <= min`, retorna `min`.
+#[inline]
+pub const fn wrap_i32(x: i32, min: i32, max: i32) -> i32 {
+    if max <= min {
+        return min;
+    }
+
+    // i64 evita overflow em x - min e max - min
+    let range = max as i64 - min as i64;
+    let offset = (x as i64 - min as i64).rem_euclid(range);
+    (min as i64 + offset) as i32
+}
+
+/// Envolve u32 no intervalo [min, max).
+///
+/// Se `max <= min`, retorna `min`.
+#[inline]
+pub const fn wrap_u32(x: u32, min: u32, max: u32) -> u32 {
+    if max <= min {
+        return min;
+    }
+
+    let range = max - min;
+    if x >= min {
+        min + (x - min) % range
+    } else {
+        // Distância abaixo de min, contada para trás a partir de max
+        let r = (min - x) % range;
+        if r == 0 {
+            min
+        } else {
+            max - r
+        }
+    }
+}
+
+/// Máximo divisor comum de inteiros com sinal.
+///
+/// O resultado ignora os sinais e é sempre não negativo
+/// (`gcd_i64(-12, 18) == 6`, `gcd_i64(0, 0) == 0`), com uma exceção:
+/// `gcd_i64(i64::MIN, 0)` e `gcd_i64(i64::MIN, i64::MIN)` valem 2^63, que
+/// não cabe em i64 e sai como `i64::MIN` (mesma convenção de
+/// `i64::MIN.wrapping_abs()`). Qualquer outro par com `i64::MIN` funciona,
+/// pois o divisor fica no máximo 2^62.
+#[inline]
+pub const fn gcd_i64(a: i64, b: i64) -> i64 {
+    gcd_u64(a.unsigned_abs(), b.unsigned_abs()) as i64
+}
+
+/// Mínimo múltiplo comum de inteiros com sinal (sempre não negativo).
+///
+/// Retorna `Some(0)` se algum argumento é zero e `None` se o resultado
+/// não cabe em i64 (por exemplo `lcm_i64(i64::MIN, 3)`).
+#[inline]
+pub const fn lcm_i64(a: i64, b: i64) -> Option<i64> {
+    if a == 0 || b == 0 {
+        return Some(0);
+    }
+
+    let (ua, ub) = (a.unsigned_abs(), b.unsigned_abs());
+    // Divide antes de multiplicar para adiar o overflow
+    match (ua / gcd_u64(ua, ub)).checked_mul(ub) {
+        Some(l) if l <= i64::MAX as u64 => Some(l as i64),
+        _ => None,
+    }
+}
+
+/// Menor potência de dois maior ou igual a `n`.
+///
+/// `bit_ceil(0) == 1`. Se o resultado não cabe em usize
+/// (n > 2^(bits - 1)) retorna 0.
+#[inline]
+pub const fn bit_ceil(n: usize) -> usize {
+    if n <= 1 {
+        return 1;
+    }
+
+    // Posição do bit mais alto de n - 1, mais um
+    let shift = usize::BITS - (n - 1).leading_zeros();
+    if shift >= usize::BITS {
+        0
+    } else {
+        1 << shift
+    }
+}
+
+/// Maior potência de dois menor ou igual a `n`.
+///
+/// `bit_floor(0) == 0`.
+#[inline]
+pub const fn bit_floor(n: usize) -> usize {
+    if n == 0 {
+        return 0;
+    }
+
+    1 << (usize::BITS - 1 - n.leading_zeros())
+}
+
+// GCD binário (Stein): só shifts e subtrações
+#[inline]
+const fn gcd_u64(mut a: u64, mut b: u64) -> u64 {
+    if a == 0 {
+        return b;
+    }
+    if b == 0 {
+        return a;
+    }
+
+    // Fatores de 2 comuns
+    let shift = (a | b).trailing_zeros();
+    a >>= a.trailing_zeros();
+
+    while b != 0 {
+        b >>= b.trailing_zeros();
+        if a > b {
+            let t = a;
+            a = b;
+            b = t;
+        }
+        b -= a;
+    }
+
+    a << shift
+}
+
+// =============================================================================
+// F64 VERSIONS
+// =============================================================================
+
+/// Clamp para f64.
+#[inline]
+pub const fn clamp64(x: f64, min: f64, max: f64) -> f64 {
+    if x < min {
+        min
+    } else if x > max {
+        max
+    } else {
+        x
+    }
+}
+
+/// Saturate para f64 (clamp entre 0 e 1).
+#[inline]
+pub const fn saturate64(x: f64) -> f64 {
+    clamp64(x, 0.0, 1.0)
+}
+
+// =============================================================================
+// DIVISÃO SEGURA
+// =============================================================================
+
+/// Divisão que retorna `fallback` quando o denominador é (quase) zero.
+///
+/// Denominadores com módulo abaixo de `DEFAULT_EPSILON_F32` (ou NaN)
+/// retornam `fallback`, evitando que inf/NaN se propaguem.
+#[inline]
+pub fn safe_divf(numerator: f32, denominator: f32, fallback: f32) -> f32 {
+    if denominator.is_nan() || absf(denominator) < DEFAULT_EPSILON_F32 {
+        return fallback;
+    }
+    numerator / denominator
+}
+
+// =============================================================================
+// EQUAÇÕES
+// =============================================================================
+
+/// Raízes reais de ax² + bx + c = 0, em ordem crescente.
+///
+/// Retorna `None` se não houver raiz real. Com `a == 0` resolve a
+/// equação linear (as duas raízes são iguais).
+#[inline]
+pub fn solve_quadraticf(a: f32, b: f32, c: f32) -> Option<(f32, f32)> {
+    if a == 0.0 {
+        if b == 0.0 {
+            return None;
+        }
+        let t = -c / b;
+        return Some((t, t));
+    }
+
+    let disc = b * b - 4.0 * a * c;
+    if disc < 0.0 {
+        return None;
+    }
+
+    // Forma estável: evita cancelamento entre -b e sqrt(disc)
+    let sq = sqrtf(disc);
+    let q = if b < 0.0 {
+        -0.5 * (b - sq)
+    } else {
+        -0.5 * (b + sq)
+    };
+
+    if q == 0.0 {
+        // b == 0 e c == 0: raiz dupla em zero
+        return Some((0.0, 0.0));
+    }
+
+    let t0 = q / a;
+    let t1 = c / q;
+
+    if t0 < t1 {
+        Some((t0, t1))
+    } else {
+        Some((t1, t0))
+    }
+}
+
 // =============================================================================
 // COMPARISON
 // =============================================================================
@@ -188,14 +890,13 @@ pub const fn clampu(x: u32, min: u32, max: u32) -> u32 {
 /// Compara floats com tolerância.
 #[inline]
 pub fn approx_eq(a: f32, b: f32, epsilon: f32) -> bool {
-    let diff = a - b;
-    (if diff < 0.0 { -diff } else { diff }) < epsilon
+    a.approx_eq_eps(b, epsilon)
 }
 
-/// Compara floats com tolerância padrão (1e-6).
+/// Compara floats com tolerância padrão (`DEFAULT_EPSILON_F32`).
 #[inline]
 pub fn nearly_eq(a: f32, b: f32) -> bool {
-    approx_eq(a, b, 1e-6)
+    a.approx_eq_default(b)
 }
 
 /// Verifica se é aproximadamente zero.
@@ -203,3 +904,518 @@ pub fn nearly_eq(a: f32, b: f32) -> bool {
 pub fn is_zero(x: f32, epsilon: f32) -> bool {
     (if x < 0.0 { -x } else { x }) < epsilon
 }
+
+/// Compara floats com tolerância relativa e absoluta (estilo PEP 485).
+///
+/// Verdadeiro se `|a - b| <= max(rel_tol * max(|a|, |b|), abs_tol)`.
+/// A tolerância absoluta domina perto de zero e a relativa para valores
+/// grandes. Infinitos só são próximos de si mesmos; NaN nunca é.
+#[inline]
+pub fn is_closef(a: f32, b: f32, rel_tol: f32, abs_tol: f32) -> bool {
+    if a == b {
+        return true;
+    }
+    if a.is_infinite() || b.is_infinite() {
+        return false;
+    }
+
+    absf(a - b) <= maxf(rel_tol * maxf(absf(a), absf(b)), abs_tol)
+}
+
+/// `is_closef` com as tolerâncias padrão do numpy (rel 1e-5, abs 1e-8).
+#[inline]
+pub fn is_close_defaultf(a: f32, b: f32) -> bool {
+    is_closef(a, b, 1e-5, 1e-8)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::vec::Vec;
+
+    #[test]
+    fn saturate64_and_clamp64_in_const_context() {
+        const SAT: [f64; 4] = [
+            saturate64(-0.5),
+            saturate64(0.25),
+            saturate64(1.0),
+            saturate64(7.0),
+        ];
+        const CLAMPED: [f64; 3] = [
+            clamp64(-3.0, -1.0, 2.0),
+            clamp64(0.5, -1.0, 2.0),
+            clamp64(9.0, -1.0, 2.0),
+        ];
+
+        assert_eq!(SAT, [0.0, 0.25, 1.0, 1.0]);
+        assert_eq!(CLAMPED, [-1.0, 0.5, 2.0]);
+    }
+
+    #[test]
+    fn selectf_picks_by_condition_bit_exactly() {
+        assert_eq!(selectf(1.5, -2.0, true), 1.5);
+        assert_eq!(selectf(1.5, -2.0, false), -2.0);
+
+        // A máscara copia os bits: sinal de zero e payload de NaN preservados
+        const NAN_PAYLOAD: f32 = f32::from_bits(0x7fc0_1234);
+        assert_eq!(selectf(-0.0, 0.0, true).to_bits(), (-0.0f32).to_bits());
+        assert_eq!(selectf(1.0, NAN_PAYLOAD, false).to_bits(), 0x7fc0_1234);
+
+        // Mesmo resultado que o if/else com desvio
+        for (a, b) in [
+            (3.0, 4.0),
+            (f32::INFINITY, -1.0),
+            (f32::MIN_POSITIVE, f32::MAX),
+        ] {
+            for cond in [true, false] {
+                let expected = if cond { a } else { b };
+                assert_eq!(selectf(a, b, cond).to_bits(), expected.to_bits());
+            }
+        }
+    }
+
+    #[test]
+    fn is_closef_abs_tol_dominates_near_zero() {
+        // rel_tol * 1e-9 é desprezível: decide a tolerância absoluta
+        assert!(is_closef(1e-9, 2e-9, 1e-5, 1e-8));
+        assert!(!is_closef(1e-9, 2e-9, 1e-5, 0.0));
+        assert!(is_closef(0.0, 5e-9, 1e-5, 1e-8));
+        assert!(!is_closef(0.0, 5e-8, 1e-5, 1e-8));
+        assert!(is_close_defaultf(0.0, -5e-9));
+    }
+
+    #[test]
+    fn is_closef_rel_tol_dominates_for_large_values() {
+        // 1e6 * 1e-5 = 10
+        assert!(is_closef(1.0e6, 1.0e6 + 8.0, 1e-5, 1e-8));
+        assert!(!is_closef(1.0e6, 1.0e6 + 16.0, 1e-5, 1e-8));
+        assert!(is_close_defaultf(1.0e10, 1.000_005e10));
+        assert!(!is_close_defaultf(1.0e10, 1.0001e10));
+        // Simétrica: a escala é o maior dos dois módulos
+        assert_eq!(
+            is_closef(100.0, 101.0, 0.01, 0.0),
+            is_closef(101.0, 100.0, 0.01, 0.0)
+        );
+    }
+
+    #[test]
+    fn is_closef_special_values() {
+        assert!(is_close_defaultf(f32::INFINITY, f32::INFINITY));
+        assert!(!is_close_defaultf(f32::INFINITY, f32::NEG_INFINITY));
+        assert!(!is_close_defaultf(f32::INFINITY, f32::MAX));
+        assert!(!is_close_defaultf(f32::NAN, f32::NAN));
+        assert!(!is_close_defaultf(f32::NAN, 0.0));
+    }
+
+    #[test]
+    fn wrap_i32_euclidean() {
+        assert_eq!(wrap_i32(-1, 0, 5), 4);
+        assert_eq!(wrap_i32(-5, 0, 5), 0);
+        assert_eq!(wrap_i32(-6, 0, 5), 4);
+        assert_eq!(wrap_i32(5, 0, 5), 0);
+        assert_eq!(wrap_i32(12, 0, 5), 2);
+        assert_eq!(wrap_i32(3, 0, 5), 3);
+        assert_eq!(wrap_i32(-13, -3, 4), 1);
+        assert_eq!(
+            wrap_i32(i32::MIN, 0, 7),
+            (i32::MIN as i64).rem_euclid(7) as i32
+        );
+        assert_eq!(wrap_i32(i32::MAX, i32::MIN, i32::MAX), i32::MIN);
+        assert_eq!(wrap_i32(9, 3, 3), 3);
+    }
+
+    #[test]
+    fn wrap_u32_below_and_above_range() {
+        const W: u32 = wrap_u32(1, 10, 15);
+        assert_eq!(W, 11);
+        assert_eq!(wrap_u32(0, 10, 15), 10);
+        assert_eq!(wrap_u32(9, 10, 15), 14);
+        assert_eq!(wrap_u32(15, 10, 15), 10);
+        assert_eq!(wrap_u32(23, 10, 15), 13);
+        assert_eq!(wrap_u32(u32::MAX, 0, 10), 5);
+        assert_eq!(wrap_u32(7, 4, 2), 4);
+    }
+
+    #[test]
+    fn crossfade_smooth_has_flat_ends() {
+        let (a, b) = (2.0, 6.0);
+        assert_eq!(crossfade_smoothf(a, b, 0.0), a);
+        assert_eq!(crossfade_smoothf(a, b, 1.0), b);
+        assert_eq!(crossfade_smoothf(a, b, 0.5), 4.0);
+        // Derivada unilateral nas pontas ~0 (a do lerp seria 4)
+        let h = 1e-3;
+        let d0 = (crossfade_smoothf(a, b, h) - crossfade_smoothf(a, b, 0.0)) / h;
+        let d1 = (crossfade_smoothf(a, b, 1.0) - crossfade_smoothf(a, b, 1.0 - h)) / h;
+        assert!(d0.abs() < 0.02, "derivada em 0: {}", d0);
+        assert!(d1.abs() < 0.02, "derivada em 1: {}", d1);
+    }
+
+    #[test]
+    fn crossfade_equal_power_weights_have_unit_energy() {
+        for i in 0..=20 {
+            let t = i as f32 / 20.0;
+            let wa = crossfade_equal_powerf(1.0, 0.0, t);
+            let wb = crossfade_equal_powerf(0.0, 1.0, t);
+            assert!((wa * wa + wb * wb - 1.0).abs() < 1e-5, "t = {}", t);
+        }
+        assert!((crossfade_equal_powerf(3.0, 7.0, 0.0) - 3.0).abs() < 1e-6);
+        assert!((crossfade_equal_powerf(3.0, 7.0, 1.0) - 7.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn remap_eased_with_smoothstep() {
+        let ease: fn(f32) -> f32 = |t| smoothstepf(0.0, 1.0, t);
+        let f = |x| remap_easedf(x, 10.0, 20.0, -1.0, 3.0, ease);
+        assert_eq!(f(10.0), -1.0);
+        assert_eq!(f(20.0), 3.0);
+        assert_eq!(f(15.0), 1.0);
+        // t = 0.25 → smoothstep 0.15625
+        assert!((f(12.5) - (-1.0 + 4.0 * 0.15625)).abs() < 1e-6);
+        // Fora do range de entrada o t é limitado
+        assert_eq!(f(0.0), -1.0);
+        assert_eq!(f(99.0), 3.0);
+    }
+
+    #[test]
+    fn clampf_nan_substitutes_fallback() {
+        assert_eq!(clampf_nan(f32::NAN, 0.0, 1.0, 0.5), 0.5);
+        assert_eq!(clampf_nan(-f32::NAN, -2.0, 2.0, -7.0), -7.0);
+        assert_eq!(clampf_nan(0.25, 0.0, 1.0, 0.5), 0.25);
+        assert_eq!(clampf_nan(-3.0, 0.0, 1.0, 0.5), 0.0);
+        assert_eq!(clampf_nan(3.0, 0.0, 1.0, 0.5), 1.0);
+        assert_eq!(clampf_nan(f32::INFINITY, 0.0, 1.0, 0.5), 1.0);
+        const C: f32 = clampf_nan(f32::NAN, 0.0, 1.0, 0.0);
+        assert_eq!(C, 0.0);
+    }
+
+    #[test]
+    fn min_max_of_three_and_four_are_const() {
+        const MIN3: f32 = min3f(3.0, -1.5, 2.0);
+        const MAX3: f32 = max3f(3.0, -1.5, 2.0);
+        const MIN4: f32 = min4f(0.5, 9.0, -4.0, 1.0);
+        const MAX4: f32 = max4f(0.5, 9.0, -4.0, 1.0);
+        assert_eq!((MIN3, MAX3, MIN4, MAX4), (-1.5, 3.0, -4.0, 9.0));
+        assert_eq!(min3f(1.0, 2.0, 0.0), 0.0);
+        assert_eq!(max3f(5.0, 2.0, 3.0), 5.0);
+        assert_eq!(min4f(2.0, 2.0, 2.0, 2.0), 2.0);
+        assert_eq!(max4f(-7.0, -3.0, -5.0, -9.0), -3.0);
+
+        const MIN3I: i32 = min3i(7, -2, 4);
+        const MAX4I: i32 = max4i(i32::MIN, 0, -1, i32::MAX);
+        const MIN4U: u32 = min4u(9, 3, 5, 8);
+        const MAX3U: u32 = max3u(1, 0, u32::MAX);
+        assert_eq!((MIN3I, MAX4I, MIN4U, MAX3U), (-2, i32::MAX, 3, u32::MAX));
+        assert_eq!(max3i(-1, -2, -3), -1);
+        assert_eq!(min4i(4, 3, 2, 1), 1);
+        assert_eq!(min3u(4, 0, 2), 0);
+        assert_eq!(max4u(1, 6, 2, 5), 6);
+    }
+
+    #[test]
+    fn remap_with_edge_modes() {
+        let f = |x, mode| remap_with_edgef(x, 0.0, 10.0, 0.0, 100.0, mode);
+        let close = |a: f32, b: f32| (a - b).abs() < 1e-4;
+
+        // Dentro do range os três modos coincidem
+        for mode in [EdgeMode::Clamp, EdgeMode::Wrap, EdgeMode::Mirror] {
+            assert!(close(f(4.0, mode), 40.0));
+        }
+
+        // t = 1.3
+        assert!(close(f(13.0, EdgeMode::Clamp), 100.0));
+        assert!(close(f(13.0, EdgeMode::Wrap), 30.0));
+        assert!(close(f(13.0, EdgeMode::Mirror), 70.0));
+
+        // t = -0.3
+        assert!(close(f(-3.0, EdgeMode::Clamp), 0.0));
+        assert!(close(f(-3.0, EdgeMode::Wrap), 70.0));
+        assert!(close(f(-3.0, EdgeMode::Mirror), 30.0));
+
+        // t = 2.25: duas voltas
+        assert!(close(f(22.5, EdgeMode::Wrap), 25.0));
+        assert!(close(f(22.5, EdgeMode::Mirror), 25.0));
+    }
+
+    #[test]
+    fn linspace_hits_endpoints_with_exact_count() {
+        for &(start, end) in &[(0.0, 1.0), (-3.7, 12.1), (5.0, -5.0), (0.1, 0.3)] {
+            for count in [2, 3, 7, 100, 1001] {
+                let it = linspacef(start, end, count);
+                assert_eq!(it.len(), count);
+                let v: Vec<f32> = it.collect();
+                assert_eq!(v.len(), count);
+                assert_eq!((v[0], v[count - 1]), (start, end));
+            }
+        }
+
+        let mut it = linspacef(0.0, 1.0, 5);
+        assert_eq!(it.nth(2), Some(0.5));
+        assert_eq!(it.len(), 2);
+        assert_eq!(linspacef(2.0, 9.0, 1).collect::<Vec<_>>(), [2.0]);
+        assert_eq!(linspacef(2.0, 9.0, 0).next(), None);
+    }
+
+    #[test]
+    fn arange_count_and_values() {
+        let mut it = arangef(0.0, 1.0, 0.25);
+        assert_eq!(it.len(), 4);
+        assert_eq!(it.next(), Some(0.0));
+        assert_eq!(it.size_hint(), (3, Some(3)));
+        assert_eq!(it.last(), Some(0.75));
+
+        // Não inclui `end` mesmo com passo que não divide o intervalo
+        let it = arangef(0.0, 1.0, 0.1);
+        assert_eq!(it.len(), 10);
+        assert!(it.last().unwrap() < 1.0);
+
+        let mut down = arangef(2.0, -1.0, -1.0);
+        assert_eq!(down.len(), 3);
+        assert_eq!(
+            (down.next(), down.next(), down.next()),
+            (Some(2.0), Some(1.0), Some(0.0))
+        );
+        assert_eq!(down.next(), None);
+
+        assert_eq!(arangef(0.0, 1.0, 0.0).len(), 0);
+        assert_eq!(arangef(0.0, 1.0, -0.5).len(), 0);
+        assert_eq!(arangef(0.0, f32::NAN, 1.0).len(), 0);
+        assert_eq!(arangef(3.0, 3.0, 1.0).len(), 0);
+    }
+
+    #[test]
+    fn arange_handles_huge_counts() {
+        // Acima de 2^24 o índice em f32 estagnaria
+        let mut it = arangef(16777216.0, 16777224.0, 2.0);
+        assert_eq!(it.len(), 4);
+        assert_eq!(it.nth(3), Some(16777222.0));
+
+        assert_eq!(arangef(0.0, 16777218.0, 1.0).len(), (1 << 24) + 2);
+        // 2^25 - 1 arredonda para 2^25 em f32, que é o próprio `end`
+        assert_eq!(arangef(0.0, 33554432.0, 1.0).len(), (1 << 25) - 1);
+        // A contagem satura em vez de estourar
+        assert_eq!(arangef(0.0, f32::INFINITY, 1.0).len(), usize::MAX);
+        assert_eq!(arangef(-1e30, 1e30, 1e-30).len(), usize::MAX);
+    }
+
+    #[test]
+    fn safe_divf_falls_back_on_tiny_denominators() {
+        assert_eq!(safe_divf(1.0, 0.0, 7.0), 7.0);
+        assert_eq!(safe_divf(1.0, -0.0, 7.0), 7.0);
+        assert_eq!(safe_divf(1.0, 1e-9, 7.0), 7.0);
+        assert_eq!(safe_divf(1.0, -1e-7, 7.0), 7.0);
+        assert_eq!(safe_divf(1.0, f32::NAN, 7.0), 7.0);
+        assert_eq!(safe_divf(6.0, 3.0, 7.0), 2.0);
+        assert_eq!(safe_divf(-1.0, 4.0, 7.0), -0.25);
+        assert_eq!(safe_divf(1.0, 1e-3, 7.0), 1.0 / 1e-3);
+    }
+
+    #[test]
+    fn fmin_fmax_skip_single_nan() {
+        assert_eq!(fminf(f32::NAN, 2.0), 2.0);
+        assert_eq!(fminf(2.0, f32::NAN), 2.0);
+        assert_eq!(fmaxf(f32::NAN, -3.0), -3.0);
+        assert_eq!(fmaxf(-3.0, f32::NAN), -3.0);
+        assert!(fminf(f32::NAN, f32::NAN).is_nan());
+        assert!(fmaxf(f32::NAN, f32::NAN).is_nan());
+        assert_eq!(fminf(1.0, -1.0), -1.0);
+        assert_eq!(fmaxf(1.0, -1.0), 1.0);
+
+        // Redução de uma caixa envolvente com um vértice NaN
+        let xs = [0.5, f32::NAN, -2.0, 3.5];
+        let (lo, hi) = xs
+            .iter()
+            .fold((f32::INFINITY, f32::NEG_INFINITY), |(lo, hi), &x| {
+                (fminf(lo, x), fmaxf(hi, x))
+            });
+        assert_eq!((lo, hi), (-2.0, 3.5));
+    }
+
+    #[test]
+    fn wrapf_repeats_range() {
+        assert!((wrapf(370.0, 0.0, 360.0) - 10.0).abs() < 1e-4);
+        assert!((wrapf(-10.0, 0.0, 360.0) - 350.0).abs() < 1e-4);
+        assert_eq!(wrapf(360.0, 0.0, 360.0), 0.0);
+        assert_eq!(wrapf(0.0, 0.0, 360.0), 0.0);
+        assert!((wrapf(-725.0, 0.0, 360.0) - 355.0).abs() < 1e-3);
+        assert!((wrapf(7.5, -2.0, 3.0) - 2.5).abs() < 1e-5);
+        assert_eq!(wrapf(5.0, 2.0, 2.0), 2.0);
+        // Resto negativo minúsculo não pode devolver `max`
+        let w = wrapf(-1e-9, 0.0, 1.0);
+        assert!((0.0..1.0).contains(&w));
+    }
+
+    #[test]
+    fn pingpong_sweep() {
+        let expected = [0.0, 1.0, 2.0, 3.0, 2.0, 1.0, 0.0, 1.0, 2.0, 3.0, 2.0];
+        for (i, &e) in expected.iter().enumerate() {
+            assert_eq!(pingpongf(i as f32, 3.0), e, "x = {}", i);
+        }
+        assert_eq!(pingpongf(-1.0, 3.0), 1.0);
+        assert_eq!(pingpongf(-4.0, 3.0), 2.0);
+        assert_eq!(pingpongf(0.5, 0.0), 0.0);
+        for i in 0..1000 {
+            let v = pingpongf(i as f32 * 0.137 - 50.0, 2.5);
+            assert!((0.0..=2.5).contains(&v));
+        }
+    }
+
+    #[test]
+    fn smoothstep_derivatives_peak_at_midpoint() {
+        let (e0, e1) = (2.0, 6.0);
+        // Picos: 1.5/largura e 1.875/largura
+        assert_eq!(smoothstep_derivf(e0, e1, 4.0), 1.5 / 4.0);
+        assert_eq!(smootherstep_derivf(e0, e1, 4.0), 1.875 / 4.0);
+        for i in 0..=40 {
+            let x = e0 + i as f32 * 0.1;
+            assert!(smoothstep_derivf(e0, e1, x) <= smoothstep_derivf(e0, e1, 4.0));
+            assert!(smootherstep_derivf(e0, e1, x) <= smootherstep_derivf(e0, e1, 4.0));
+        }
+
+        // Zero nas pontas, fora da faixa e com largura nula
+        for x in [e0, e1, -10.0, 10.0] {
+            assert_eq!(smoothstep_derivf(e0, e1, x), 0.0);
+            assert_eq!(smootherstep_derivf(e0, e1, x), 0.0);
+        }
+        assert_eq!(smoothstep_derivf(3.0, 3.0, 3.0), 0.0);
+        assert_eq!(smootherstep_derivf(3.0, 3.0, 3.0), 0.0);
+    }
+
+    #[test]
+    fn smoothstep_derivatives_match_finite_difference() {
+        let (e0, e1) = (-1.0, 3.0);
+        let h = 1e-3;
+        for i in 1..20 {
+            let x = e0 + i as f32 * 0.2;
+            let d = (smoothstepf(e0, e1, x + h) - smoothstepf(e0, e1, x - h)) / (2.0 * h);
+            assert!((smoothstep_derivf(e0, e1, x) - d).abs() < 1e-3, "x = {}", x);
+            let d = (smootherstepf(e0, e1, x + h) - smootherstepf(e0, e1, x - h)) / (2.0 * h);
+            assert!(
+                (smootherstep_derivf(e0, e1, x) - d).abs() < 1e-3,
+                "x = {}",
+                x
+            );
+        }
+    }
+
+    #[test]
+    fn clamp_report_flags_out_of_range() {
+        assert_eq!(clamp_reportf(0.5, 0.0, 1.0), (0.5, false));
+        assert_eq!(clamp_reportf(-3.0, 0.0, 1.0), (0.0, true));
+        assert_eq!(clamp_reportf(7.0, 0.0, 1.0), (1.0, true));
+        // Nos limites não há clamp
+        assert_eq!(clamp_reportf(0.0, 0.0, 1.0), (0.0, false));
+        assert_eq!(clamp_reportf(1.0, 0.0, 1.0), (1.0, false));
+    }
+
+    #[test]
+    fn gcd_lcm_i64_signs() {
+        assert_eq!(gcd_i64(-12, 18), 6);
+        assert_eq!(gcd_i64(12, -18), 6);
+        assert_eq!(gcd_i64(-12, -18), 6);
+        assert_eq!(gcd_i64(0, -7), 7);
+        assert_eq!(gcd_i64(0, 0), 0);
+
+        assert_eq!(lcm_i64(-4, 6), Some(12));
+        assert_eq!(lcm_i64(4, -6), Some(12));
+        assert_eq!(lcm_i64(0, -6), Some(0));
+        assert_eq!(lcm_i64(i64::MAX, i64::MAX - 1), None);
+    }
+
+    #[test]
+    fn gcd_lcm_i64_min_boundary() {
+        assert_eq!(gcd_i64(i64::MIN, 6), 2);
+        assert_eq!(gcd_i64(i64::MIN, -(1 << 40)), 1 << 40);
+        assert_eq!(gcd_i64(i64::MIN, i64::MAX), 1);
+        // 2^63 não cabe em i64: sai como i64::MIN
+        assert_eq!(gcd_i64(i64::MIN, 0), i64::MIN);
+        assert_eq!(gcd_i64(i64::MIN, i64::MIN), i64::MIN);
+
+        assert_eq!(lcm_i64(i64::MIN, 3), None);
+        assert_eq!(lcm_i64(i64::MIN, 2), None);
+        assert_eq!(lcm_i64(i64::MIN / 2, 2), Some(1 << 62));
+    }
+
+    #[test]
+    fn srgb_known_values() {
+        assert!((linear_to_srgbf(0.5) - 0.735).abs() < 1e-3);
+        assert!((srgb_to_linearf(0.735) - 0.5).abs() < 1e-3);
+        assert_eq!(linear_to_srgbf(0.0), 0.0);
+        assert!((linear_to_srgbf(1.0) - 1.0).abs() < 1e-6);
+        // Trecho linear perto do preto
+        assert!((linear_to_srgbf(0.002) - 0.02584).abs() < 1e-6);
+        assert!((srgb_to_linearf(0.02) - 0.02 / 12.92).abs() < 1e-9);
+        // Entrada fora de [0, 1] é saturada
+        assert_eq!(srgb_to_linearf(-1.0), 0.0);
+        assert_eq!(linear_to_srgbf(2.0), linear_to_srgbf(1.0));
+    }
+
+    #[test]
+    fn srgb_round_trip_is_stable() {
+        for i in 0..=1000 {
+            let c = i as f32 / 1000.0;
+            let back = srgb_to_linearf(linear_to_srgbf(c));
+            assert!((back - c).abs() < 1e-4, "linear {}: {}", c, back);
+            let back = linear_to_srgbf(srgb_to_linearf(c));
+            assert!((back - c).abs() < 1e-4, "sRGB {}: {}", c, back);
+        }
+    }
+
+    #[test]
+    fn bit_ceil_floor_in_const_context() {
+        const CEIL: [usize; 5] = [
+            bit_ceil(0),
+            bit_ceil(1),
+            bit_ceil(7),
+            bit_ceil(8),
+            bit_ceil(9),
+        ];
+        const FLOOR: [usize; 5] = [
+            bit_floor(0),
+            bit_floor(1),
+            bit_floor(7),
+            bit_floor(8),
+            bit_floor(9),
+        ];
+        assert_eq!(CEIL, [1, 1, 8, 8, 16]);
+        assert_eq!(FLOOR, [0, 1, 4, 8, 8]);
+
+        // Tamanho de buffer calculado em tempo de compilação
+        let buffer = [0u8; bit_ceil(100)];
+        assert_eq!(buffer.len(), 128);
+
+        // Pontas de usize
+        const TOP: usize = 1 << (usize::BITS - 1);
+        assert_eq!(bit_ceil(TOP), TOP);
+        assert_eq!(bit_ceil(TOP + 1), 0);
+        assert_eq!(bit_floor(usize::MAX), TOP);
+    }
+
+    #[test]
+    fn smooth_falloff_bump() {
+        let (center, edge) = (2.0, 5.0);
+        assert_eq!(smooth_fallofff(center, edge, center), 1.0);
+        assert_eq!(smooth_fallofff(center, edge, 5.0), 0.0);
+        assert_eq!(smooth_fallofff(center, edge, -1.0), 0.0);
+        assert_eq!(smooth_fallofff(center, edge, 9.0), 0.0);
+        assert_eq!(smooth_fallofff(center, edge, -20.0), 0.0);
+
+        let mut prev = 1.0;
+        for i in 1..=30 {
+            let d = i as f32 * 0.1;
+            let right = smooth_fallofff(center, edge, center + d);
+            let left = smooth_fallofff(center, edge, center - d);
+            assert!((right - left).abs() < 1e-6, "d = {}", d);
+            assert!(right <= prev, "d = {}", d);
+            prev = right;
+        }
+        // Metade do caminho: 1 - smoothstep(0.5)
+        assert!((smooth_fallofff(center, edge, 3.5) - 0.5).abs() < 1e-6);
+        // Borda antes do centro dá a mesma curva
+        assert_eq!(
+            smooth_fallofff(center, -1.0, 3.0),
+            smooth_fallofff(center, edge, 3.0)
+        );
+        assert_eq!(smooth_fallofff(1.0, 1.0, 1.0), 1.0);
+        assert_eq!(smooth_fallofff(1.0, 1.0, 1.1), 0.0);
+    }
+}
@@ -0,0 +1,687 @@
+//! # Vetores
+//!
+//! Tipos vetoriais para matemática gráfica.
+
+use core::ops::{Add, Neg, Sub};
+
+use crate::exp::{hypotf, rsqrtf, sqrtf};
+use crate::round::{absf, roundf};
+use crate::trig::{atan2f, sincosf};
+use crate::util::{clampf, lerpf, safe_divf, selectf};
+
+// =============================================================================
+// VEC2
+// =============================================================================
+
+/// Vetor 2D.
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub struct Vec2 {
+    pub x: f32,
+    pub y: f32,
+}
+
+impl Vec2 {
+    /// Vetor nulo.
+    pub const ZERO: Vec2 = Vec2::new(0.0, 0.0);
+
+    /// Cria um vetor.
+    #[inline]
+    pub const fn new(x: f32, y: f32) -> Self {
+        Self { x, y }
+    }
+
+    /// Vetor unitário na direção do ângulo (0 aponta para +X).
+    #[inline]
+    pub fn from_angle(angle: f32) -> Vec2 {
+        let (s, c) = sincosf(angle);
+        Vec2::new(c, s)
+    }
+
+    /// Ângulo do vetor em [-π, π] (0 aponta para +X).
+    #[inline]
+    pub fn to_angle(self) -> f32 {
+        atan2f(self.y, self.x)
+    }
+
+    /// Produto escalar.
+    #[inline]
+    pub fn dot(self, other: Vec2) -> f32 {
+        self.x * other.x + self.y * other.y
+    }
+
+    /// Comprimento ao quadrado (evita a raiz).
+    #[inline]
+    pub fn length_squared(self) -> f32 {
+        self.dot(self)
+    }
+
+    /// Comprimento (norma euclidiana).
+    #[inline]
+    pub fn length(self) -> f32 {
+        hypotf(self.x, self.y)
+    }
+
+    /// Vetor unitário na mesma direção. Vetor nulo retorna nulo.
+    #[inline]
+    pub fn normalize(self) -> Vec2 {
+        self.scale(rsqrtf(self.length_squared()))
+    }
+
+    /// Limita o comprimento a 1: vetores mais longos são reescalados para
+    /// comprimento unitário, os mais curtos ficam inalterados.
+    #[inline]
+    pub fn clamp_to_unit(self) -> Vec2 {
+        let len_sq = self.length_squared();
+        if len_sq > 1.0 {
+            self.scale(rsqrtf(len_sq))
+        } else {
+            self
+        }
+    }
+
+    /// Multiplica todos os componentes por um escalar.
+    #[inline]
+    pub fn scale(self, s: f32) -> Vec2 {
+        Vec2::new(self.x * s, self.y * s)
+    }
+}
+
+impl Add for Vec2 {
+    type Output = Vec2;
+
+    #[inline]
+    fn add(self, rhs: Vec2) -> Vec2 {
+        Vec2::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl Sub for Vec2 {
+    type Output = Vec2;
+
+    #[inline]
+    fn sub(self, rhs: Vec2) -> Vec2 {
+        Vec2::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl Neg for Vec2 {
+    type Output = Vec2;
+
+    #[inline]
+    fn neg(self) -> Vec2 {
+        Vec2::new(-self.x, -self.y)
+    }
+}
+
+// =============================================================================
+// VEC3
+// =============================================================================
+
+/// Vetor 3D.
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub struct Vec3 {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+}
+
+impl Vec3 {
+    /// Vetor nulo.
+    pub const ZERO: Vec3 = Vec3::new(0.0, 0.0, 0.0);
+
+    /// Cria um vetor.
+    #[inline]
+    pub const fn new(x: f32, y: f32, z: f32) -> Self {
+        Self { x, y, z }
+    }
+
+    /// Produto escalar.
+    #[inline]
+    pub fn dot(self, other: Vec3) -> f32 {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    /// Comprimento ao quadrado (evita a raiz).
+    #[inline]
+    pub fn length_squared(self) -> f32 {
+        self.dot(self)
+    }
+
+    /// Comprimento (norma euclidiana).
+    #[inline]
+    pub fn length(self) -> f32 {
+        sqrtf(self.length_squared())
+    }
+
+    /// Produto vetorial (regra da mão direita: X × Y = Z).
+    #[inline]
+    pub fn cross(self, other: Vec3) -> Vec3 {
+        Vec3::new(
+            self.y * other.z - self.z * other.y,
+            self.z * other.x - self.x * other.z,
+            self.x * other.y - self.y * other.x,
+        )
+    }
+
+    /// Reflexão em torno da normal: `v - 2(v·n)n`.
+    ///
+    /// Assume `normal` normalizada.
+    #[inline]
+    pub fn reflect(self, normal: Vec3) -> Vec3 {
+        self - normal.scale(2.0 * self.dot(normal))
+    }
+
+    /// Distância euclidiana até outro ponto.
+    #[inline]
+    pub fn distance(self, other: Vec3) -> f32 {
+        (self - other).length()
+    }
+
+    /// Interpolação linear por componente (t = 0 → `self`, t = 1 → `other`).
+    #[inline]
+    pub fn lerp(self, other: Vec3, t: f32) -> Vec3 {
+        Vec3::new(
+            lerpf(self.x, other.x, t),
+            lerpf(self.y, other.y, t),
+            lerpf(self.z, other.z, t),
+        )
+    }
+
+    /// Vetor unitário na mesma direção. Vetor nulo retorna nulo.
+    #[inline]
+    pub fn normalize(self) -> Vec3 {
+        self.scale(rsqrtf(self.length_squared()))
+    }
+
+    /// Limita o comprimento a 1: vetores mais longos são reescalados para
+    /// comprimento unitário, os mais curtos ficam inalterados.
+    #[inline]
+    pub fn clamp_to_unit(self) -> Vec3 {
+        let len_sq = self.length_squared();
+        if len_sq > 1.0 {
+            self.scale(rsqrtf(len_sq))
+        } else {
+            self
+        }
+    }
+
+    /// Multiplica todos os componentes por um escalar.
+    #[inline]
+    pub fn scale(self, s: f32) -> Vec3 {
+        Vec3::new(self.x * s, self.y * s, self.z * s)
+    }
+
+    /// Divisão por componente com `fallback` onde o divisor é (quase) zero.
+    ///
+    /// Ver [`safe_divf`].
+    #[inline]
+    pub fn safe_div(self, rhs: Vec3, fallback: Vec3) -> Vec3 {
+        Vec3::new(
+            safe_divf(self.x, rhs.x, fallback.x),
+            safe_divf(self.y, rhs.y, fallback.y),
+            safe_divf(self.z, rhs.z, fallback.z),
+        )
+    }
+
+    /// Seleção sem desvio: `a` se `cond` for verdadeiro, senão `b`.
+    #[inline]
+    pub fn select(a: Vec3, b: Vec3, cond: bool) -> Vec3 {
+        Vec3::new(
+            selectf(a.x, b.x, cond),
+            selectf(a.y, b.y, cond),
+            selectf(a.z, b.z, cond),
+        )
+    }
+}
+
+impl Add for Vec3 {
+    type Output = Vec3;
+
+    #[inline]
+    fn add(self, rhs: Vec3) -> Vec3 {
+        Vec3::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
+
+impl Sub for Vec3 {
+    type Output = Vec3;
+
+    #[inline]
+    fn sub(self, rhs: Vec3) -> Vec3 {
+        Vec3::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
+
+impl Neg for Vec3 {
+    type Output = Vec3;
+
+    #[inline]
+    fn neg(self) -> Vec3 {
+        Vec3::new(-self.x, -self.y, -self.z)
+    }
+}
+
+// =============================================================================
+// VECN
+// =============================================================================
+
+/// Vetor de dimensão arbitrária `N`, sem alocação.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct VecN<const N: usize>(pub [f32; N]);
+
+impl<const N: usize> VecN<N> {
+    /// Vetor nulo.
+    pub const ZERO: VecN<N> = VecN([0.0; N]);
+
+    /// Cria um vetor a partir dos componentes.
+    #[inline]
+    pub const fn new(components: [f32; N]) -> Self {
+        Self(components)
+    }
+
+    /// Produto escalar.
+    #[inline]
+    pub const fn dot(&self, other: &VecN<N>) -> f32 {
+        dot_n(&self.0, &other.0)
+    }
+
+    /// Comprimento ao quadrado (evita a raiz).
+    #[inline]
+    pub const fn length_squared(&self) -> f32 {
+        self.dot(self)
+    }
+
+    /// Comprimento (norma euclidiana).
+    #[inline]
+    pub fn length(&self) -> f32 {
+        sqrtf(self.length_squared())
+    }
+
+    /// Distância euclidiana até outro ponto.
+    #[inline]
+    pub fn distance(&self, other: &VecN<N>) -> f32 {
+        distance_n(&self.0, &other.0)
+    }
+
+    /// Multiplica todos os componentes por um escalar.
+    #[inline]
+    pub fn scale(mut self, s: f32) -> VecN<N> {
+        for c in self.0.iter_mut() {
+            *c *= s;
+        }
+        self
+    }
+
+    /// Vetor unitário na mesma direção. Vetor nulo retorna nulo.
+    #[inline]
+    pub fn normalize(self) -> VecN<N> {
+        let inv = rsqrtf(self.length_squared());
+        self.scale(inv)
+    }
+}
+
+impl<const N: usize> Default for VecN<N> {
+    #[inline]
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl<const N: usize> Add for VecN<N> {
+    type Output = VecN<N>;
+
+    #[inline]
+    fn add(mut self, rhs: VecN<N>) -> VecN<N> {
+        for (a, b) in self.0.iter_mut().zip(rhs.0.iter()) {
+            *a += *b;
+        }
+        self
+    }
+}
+
+impl<const N: usize> Sub for VecN<N> {
+    type Output = VecN<N>;
+
+    #[inline]
+    fn sub(mut self, rhs: VecN<N>) -> VecN<N> {
+        for (a, b) in self.0.iter_mut().zip(rhs.0.iter()) {
+            *a -= *b;
+        }
+        self
+    }
+}
+
+impl<const N: usize> Neg for VecN<N> {
+    type Output = VecN<N>;
+
+    #[inline]
+    fn neg(self) -> VecN<N> {
+        self.scale(-1.0)
+    }
+}
+
+// =============================================================================
+// CODIFICAÇÃO OCTAÉDRICA
+// =============================================================================
+
+/// Codifica uma direção unitária em 2D (mapeamento octaédrico).
+///
+/// Projeta no octaedro |x| + |y| + |z| = 1 e dobra o hemisfério
+/// inferior sobre o superior, resultando em (u, v) em [-1, 1]².
+/// Vetor nulo codifica como (0, 0), que decodifica para +Z.
+#[inline]
+pub fn encode_octahedral(n: Vec3) -> (f32, f32) {
+    let l1 = absf(n.x) + absf(n.y) + absf(n.z);
+    if l1 == 0.0 {
+        return (0.0, 0.0);
+    }
+
+    let u = n.x / l1;
+    let v = n.y / l1;
+
+    if n.z >= 0.0 {
+        (u, v)
+    } else {
+        (
+            (1.0 - absf(v)) * sign_not_zero(u),
+            (1.0 - absf(u)) * sign_not_zero(v),
+        )
+    }
+}
+
+/// Decodifica (u, v) de [`encode_octahedral`] para uma direção unitária.
+#[inline]
+pub fn decode_octahedral(u: f32, v: f32) -> Vec3 {
+    let z = 1.0 - absf(u) - absf(v);
+
+    let (x, y) = if z >= 0.0 {
+        (u, v)
+    } else {
+        (
+            (1.0 - absf(v)) * sign_not_zero(u),
+            (1.0 - absf(u)) * sign_not_zero(v),
+        )
+    };
+
+    Vec3::new(x, y, z).normalize()
+}
+
+/// Codifica uma direção unitária em 32 bits (dois snorm de 16 bits).
+///
+/// Erro angular máximo em torno de 0.005°.
+#[inline]
+pub fn encode_octahedral_u32(n: Vec3) -> u32 {
+    let (u, v) = encode_octahedral(n);
+    (to_snorm16(u) as u32) | ((to_snorm16(v) as u32) << 16)
+}
+
+/// Decodifica uma direção de [`encode_octahedral_u32`].
+#[inline]
+pub fn decode_octahedral_u32(packed: u32) -> Vec3 {
+    let u = (packed & 0xffff) as u16 as i16 as f32 * (1.0 / 32767.0);
+    let v = (packed >> 16) as u16 as i16 as f32 * (1.0 / 32767.0);
+    decode_octahedral(u, v)
+}
+
+// Como signf, mas 0 conta como positivo (a dobra precisa de ±1)
+#[inline]
+fn sign_not_zero(x: f32) -> f32 {
+    if x >= 0.0 {
+        1.0
+    } else {
+        -1.0
+    }
+}
+
+#[inline]
+fn to_snorm16(x: f32) -> u16 {
+    roundf(clampf(x, -1.0, 1.0) * 32767.0) as i16 as u16
+}
+
+// =============================================================================
+// ARRAYS N-DIMENSIONAIS
+// =============================================================================
+
+/// Produto escalar de dois arrays de dimensão `N`.
+#[inline]
+pub const fn dot_n<const N: usize>(a: &[f32; N], b: &[f32; N]) -> f32 {
+    let mut sum = 0.0;
+    let mut i = 0;
+    while i < N {
+        sum += a[i] * b[i];
+        i += 1;
+    }
+    sum
+}
+
+/// Distância euclidiana entre dois arrays de dimensão `N`.
+#[inline]
+pub fn distance_n<const N: usize>(a: &[f32; N], b: &[f32; N]) -> f32 {
+    let mut sum = 0.0;
+    for i in 0..N {
+        let d = a[i] - b[i];
+        sum += d * d;
+    }
+    sqrtf(sum)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn vec3_select_per_condition() {
+        let a = Vec3::new(1.0, 2.0, 3.0);
+        let b = Vec3::new(-1.0, -2.0, -3.0);
+        assert_eq!(Vec3::select(a, b, true), a);
+        assert_eq!(Vec3::select(a, b, false), b);
+    }
+
+    #[test]
+    fn dot_n_five_dimensions() {
+        const A: [f32; 5] = [1.0, 2.0, 3.0, 4.0, 5.0];
+        const B: [f32; 5] = [6.0, 7.0, 8.0, 9.0, 10.0];
+        // 6 + 14 + 24 + 36 + 50
+        const DOT: f32 = dot_n(&A, &B);
+        assert_eq!(DOT, 130.0);
+        assert_eq!(dot_n(&A, &A), 55.0);
+    }
+
+    #[test]
+    fn distance_n_five_dimensions() {
+        let a = [1.0, 2.0, 3.0, 4.0, 5.0];
+        let b = [6.0, 7.0, 8.0, 9.0, 10.0];
+        // sqrt(5 * 5²) = sqrt(125)
+        assert!((distance_n(&a, &b) - 11.180_34).abs() < 1e-5);
+        assert_eq!(distance_n(&a, &a), 0.0);
+        assert!((distance_n(&[0.0, 3.0, 0.0, 4.0, 0.0], &[0.0; 5]) - 5.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn vec2_angle_round_trip() {
+        use crate::consts::FRAC_PI_2;
+
+        let up = Vec2::from_angle(FRAC_PI_2);
+        assert!(up.x.abs() < 1e-6 && (up.y - 1.0).abs() < 1e-6);
+        assert_eq!(Vec2::new(1.0, 0.0).to_angle(), 0.0);
+
+        for i in -31..=31 {
+            let angle = i as f32 * 0.1;
+            let v = Vec2::from_angle(angle);
+            assert!((v.length() - 1.0).abs() < 1e-5);
+            assert!((v.to_angle() - angle).abs() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn vec3_safe_div_per_component() {
+        let a = Vec3::new(6.0, 1.0, -2.0);
+        let b = Vec3::new(3.0, 0.0, 1e-9);
+        let fallback = Vec3::new(-1.0, -2.0, -3.0);
+        assert_eq!(a.safe_div(b, fallback), Vec3::new(2.0, -2.0, -3.0));
+    }
+
+    #[test]
+    fn normalize_gives_unit_length() {
+        for &(x, y, z) in &[
+            (3.0, 4.0, 0.0),
+            (1e-3, -2e-3, 5e-4),
+            (-120.0, 45.0, 9.5),
+            (0.0, 0.0, 2.0),
+        ] {
+            let v3 = Vec3::new(x, y, z).normalize();
+            assert!((v3.length() - 1.0).abs() < 1e-4, "{:?}", v3);
+            let v2 = Vec2::new(x, y + 1.0).normalize();
+            assert!((v2.length() - 1.0).abs() < 1e-4, "{:?}", v2);
+        }
+        assert_eq!(Vec2::ZERO.normalize(), Vec2::ZERO);
+        assert_eq!(Vec3::ZERO.normalize(), Vec3::ZERO);
+    }
+
+    #[test]
+    fn vector_operators() {
+        let a = Vec2::new(1.0, -2.0);
+        let b = Vec2::new(0.5, 4.0);
+        assert_eq!(a + b, Vec2::new(1.5, 2.0));
+        assert_eq!(a - b, Vec2::new(0.5, -6.0));
+        assert_eq!(-a, Vec2::new(-1.0, 2.0));
+        assert_eq!(a.dot(b), -7.5);
+        assert_eq!(a.scale(2.0), Vec2::new(2.0, -4.0));
+        assert_eq!(Vec2::new(3.0, 4.0).length_squared(), 25.0);
+        assert!((Vec2::new(3.0, 4.0).length() - 5.0).abs() < 1e-5);
+
+        let c = Vec3::new(1.0, 2.0, 3.0);
+        let d = Vec3::new(-4.0, 0.5, 2.0);
+        assert_eq!(c + d, Vec3::new(-3.0, 2.5, 5.0));
+        assert_eq!(c - d, Vec3::new(5.0, 1.5, 1.0));
+        assert_eq!(-c, Vec3::new(-1.0, -2.0, -3.0));
+        assert_eq!(c.dot(d), 3.0);
+        assert_eq!(c.scale(-1.0), -c);
+        assert_eq!(c.length_squared(), 14.0);
+
+        const ORIGIN: Vec3 = Vec3::new(0.0, 0.0, 0.0);
+        assert_eq!(ORIGIN, Vec3::ZERO);
+    }
+
+    #[test]
+    fn cross_of_basis_vectors() {
+        let x = Vec3::new(1.0, 0.0, 0.0);
+        let y = Vec3::new(0.0, 1.0, 0.0);
+        let z = Vec3::new(0.0, 0.0, 1.0);
+        assert_eq!(x.cross(y), z);
+        assert_eq!(y.cross(z), x);
+        assert_eq!(z.cross(x), y);
+        assert_eq!(y.cross(x), -z);
+        assert_eq!(x.cross(x), Vec3::ZERO);
+    }
+
+    #[test]
+    fn reflect_off_axis_planes_flips_one_component() {
+        let v = Vec3::new(1.0, -2.0, 3.0);
+        assert_eq!(
+            v.reflect(Vec3::new(0.0, 1.0, 0.0)),
+            Vec3::new(1.0, 2.0, 3.0)
+        );
+        assert_eq!(
+            v.reflect(Vec3::new(1.0, 0.0, 0.0)),
+            Vec3::new(-1.0, -2.0, 3.0)
+        );
+        assert_eq!(
+            v.reflect(Vec3::new(0.0, 0.0, -1.0)),
+            Vec3::new(1.0, -2.0, -3.0)
+        );
+    }
+
+    #[test]
+    fn vec3_distance_and_lerp() {
+        let a = Vec3::new(1.0, 2.0, 3.0);
+        let b = Vec3::new(4.0, 6.0, 3.0);
+        assert!((a.distance(b) - 5.0).abs() < 1e-5);
+        assert_eq!(a.lerp(b, 0.0), a);
+        assert_eq!(a.lerp(b, 1.0), b);
+        assert_eq!(a.lerp(b, 0.5), Vec3::new(2.5, 4.0, 3.0));
+    }
+
+    #[test]
+    fn clamp_to_unit_only_shrinks_long_vectors() {
+        let long2 = Vec2::new(0.0, 2.0).clamp_to_unit();
+        assert!((long2.length() - 1.0).abs() < 1e-5);
+        assert!(long2.x.abs() < 1e-6 && long2.y > 0.0);
+        let short2 = Vec2::new(0.3, 0.4);
+        assert_eq!(short2.clamp_to_unit(), short2);
+
+        let long3 = Vec3::new(2.0, 0.0, 0.0).clamp_to_unit();
+        assert!((long3.x - 1.0).abs() < 1e-5 && long3.y == 0.0 && long3.z == 0.0);
+        let diag = Vec3::new(3.0, -4.0, 12.0).clamp_to_unit();
+        assert!((diag.length() - 1.0).abs() < 1e-5);
+        let short3 = Vec3::new(0.5, 0.0, 0.0);
+        assert_eq!(short3.clamp_to_unit(), short3);
+        assert_eq!(Vec3::ZERO.clamp_to_unit(), Vec3::ZERO);
+    }
+
+    #[test]
+    fn vecn_six_dimensions() {
+        let a = VecN::new([1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        let b = VecN::new([6.0, 5.0, 4.0, 3.0, 2.0, 1.0]);
+        // 6 + 10 + 12 + 12 + 10 + 6
+        assert_eq!(a.dot(&b), 56.0);
+        assert_eq!(a.length_squared(), 91.0);
+        assert!((a.length() - 9.539_392).abs() < 1e-4);
+        // Diferenças ±5, ±3, ±1: sqrt(70)
+        assert!((a.distance(&b) - 8.366_600).abs() < 1e-4);
+
+        let n = a.normalize();
+        assert!((n.length() - 1.0).abs() < 1e-5);
+        assert!((n.0[5] / n.0[0] - 6.0).abs() < 1e-4);
+        assert_eq!(VecN::<6>::ZERO.normalize(), VecN::ZERO);
+
+        assert_eq!(a + b, VecN::new([7.0; 6]));
+        assert_eq!(a - b, VecN::new([-5.0, -3.0, -1.0, 1.0, 3.0, 5.0]));
+        assert_eq!(-a, a.scale(-1.0));
+        assert_eq!(a.scale(0.5).0[3], 2.0);
+    }
+
+    #[test]
+    fn octahedral_round_trip_random_directions() {
+        use crate::rng::{random_on_unit_spheref, Pcg32};
+
+        // Ângulo entre direções, em graus (atan2 em f64 é estável perto de 0)
+        let angle = |a: Vec3, b: Vec3| {
+            let c = a.cross(b);
+            let sin = ((c.x * c.x + c.y * c.y + c.z * c.z) as f64).sqrt();
+            sin.atan2(a.dot(b) as f64).to_degrees() as f32
+        };
+
+        let mut rng = Pcg32::new(516);
+        let mut worst_f32 = 0.0f32;
+        let mut worst_u32 = 0.0f32;
+        for _ in 0..20_000 {
+            let (x, y, z) = random_on_unit_spheref(&mut rng);
+            let n = Vec3::new(x, y, z).normalize();
+
+            let (u, v) = encode_octahedral(n);
+            assert!((-1.0..=1.0).contains(&u) && (-1.0..=1.0).contains(&v));
+            worst_f32 = worst_f32.max(angle(n, decode_octahedral(u, v)));
+            worst_u32 = worst_u32.max(angle(n, decode_octahedral_u32(encode_octahedral_u32(n))));
+        }
+        assert!(worst_f32 < 1e-4, "f32: {}°", worst_f32);
+        // O erro documentado para 16 bits por eixo
+        assert!(worst_u32 < 0.005, "u32: {}°", worst_u32);
+    }
+
+    #[test]
+    fn octahedral_axes_and_zero() {
+        for n in [
+            Vec3::new(1.0, 0.0, 0.0),
+            Vec3::new(0.0, -1.0, 0.0),
+            Vec3::new(0.0, 0.0, 1.0),
+            Vec3::new(0.0, 0.0, -1.0),
+        ] {
+            let (u, v) = encode_octahedral(n);
+            let d = decode_octahedral(u, v);
+            assert!((d - n).length() < 1e-5, "{:?} -> {:?}", n, d);
+        }
+        assert_eq!(encode_octahedral(Vec3::ZERO), (0.0, 0.0));
+        assert!((decode_octahedral(0.0, 0.0) - Vec3::new(0.0, 0.0, 1.0)).length() < 1e-5);
+    }
+}
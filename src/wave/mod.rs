@@ -0,0 +1,209 @@
+//! # Ondas e Osciladores
+//!
+//! Acumuladores de fase e formas de onda periódicas.
+
+use crate::consts::TAU;
+use crate::round::{absf, fractf};
+use crate::trig::sinf;
+
+// =============================================================================
+// ACUMULADOR DE FASE
+// =============================================================================
+
+/// Acumulador de fase normalizada para osciladores.
+///
+/// A fase armazenada fica sempre em [0, 1), então não perde precisão
+/// por crescimento ao longo de execuções longas. A soma é compensada
+/// (Kahan), evitando a deriva do arredondamento de cada incremento.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct PhaseAccumulator {
+    phase: f32,
+    comp: f32,
+}
+
+impl PhaseAccumulator {
+    /// Cria acumulador com fase 0.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            phase: 0.0,
+            comp: 0.0,
+        }
+    }
+
+    /// Avança a fase (em ciclos) e retorna a nova fase em [0, 1).
+    ///
+    /// Para um oscilador de frequência `f` a taxa `sr`, use
+    /// `increment = f / sr`. Incrementos negativos também funcionam.
+    #[inline]
+    pub fn advance(&mut self, increment: f32) -> f32 {
+        // Soma compensada; a subtração do floor em fractf é exata
+        let y = increment - self.comp;
+        let t = self.phase + y;
+        self.comp = (t - self.phase) - y;
+
+        let mut p = fractf(t);
+
+        // fractf de um valor levemente negativo pode arredondar para 1.0
+        if p >= 1.0 {
+            p = 0.0;
+        }
+
+        self.phase = p;
+        p
+    }
+
+    /// Fase atual em [0, 1).
+    #[inline]
+    pub const fn phase(&self) -> f32 {
+        self.phase
+    }
+
+    /// Reinicia a fase para 0.
+    #[inline]
+    pub fn reset(&mut self) {
+        self.phase = 0.0;
+        self.comp = 0.0;
+    }
+}
+
+// =============================================================================
+// FORMAS DE ONDA
+// =============================================================================
+
+/// Onda senoidal com fase em ciclos (período 1), em [-1, 1].
+#[inline]
+pub fn sine_wavef(phase: f32) -> f32 {
+    sinf(TAU * fractf(phase))
+}
+
+/// Onda triangular com fase em ciclos (período 1), em [-1, 1].
+///
+/// Alinhada com o seno: 0 em 0, 1 em 0.25, -1 em 0.75.
+#[inline]
+pub fn triangle_wavef(phase: f32) -> f32 {
+    1.0 - 4.0 * absf(fractf(phase + 0.25) - 0.5)
+}
+
+/// Onda de pulso com fase em ciclos (período 1).
+///
+/// Vale 1 enquanto a fase está abaixo de `duty` (em [0, 1]) e -1 no
+/// resto do ciclo. `duty = 0.5` dá uma onda quadrada.
+#[inline]
+pub fn pulse_wavef(phase: f32, duty: f32) -> f32 {
+    if fractf(phase) < duty {
+        1.0
+    } else {
+        -1.0
+    }
+}
+
+// =============================================================================
+// OSCILAÇÃO NO TEMPO
+// =============================================================================
+
+/// Oscilação senoidal: `offset + amplitude * sin(2π * frequency * time)`.
+///
+/// `frequency` em ciclos por unidade de tempo; a saída fica em
+/// [offset - amplitude, offset + amplitude].
+#[inline]
+pub fn oscillate_sinf(time: f32, frequency: f32, amplitude: f32, offset: f32) -> f32 {
+    offset + amplitude * sine_wavef(time * frequency)
+}
+
+/// Oscilação triangular, com a mesma convenção de [`oscillate_sinf`].
+#[inline]
+pub fn oscillate_trianglef(time: f32, frequency: f32, amplitude: f32, offset: f32) -> f32 {
+    offset + amplitude * triangle_wavef(time * frequency)
+}
+
+/// Oscilação de pulso entre `offset + amplitude` e `offset - amplitude`.
+///
+/// Fica no valor alto durante a fração `duty` de cada período.
+#[inline]
+pub fn oscillate_pulsef(time: f32, frequency: f32, amplitude: f32, offset: f32, duty: f32) -> f32 {
+    offset + amplitude * pulse_wavef(time * frequency, duty)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn phase_accumulator_stays_bounded_and_tracks_f64() {
+        let inc: f32 = 440.0 / 48_000.0;
+        let mut acc = PhaseAccumulator::new();
+        let mut reference = 0.0f64;
+
+        for i in 0..5_000_000u32 {
+            let p = acc.advance(inc);
+            assert!((0.0..1.0).contains(&p), "passo {i}: fase {p}");
+
+            reference = (reference + inc as f64).fract();
+            if i % 100_000 == 0 {
+                // Distância circular entre as fases
+                let d = (p as f64 - reference).abs();
+                let d = d.min(1.0 - d);
+                assert!(d < 1e-6, "passo {i}: desvio {d}");
+            }
+        }
+    }
+
+    #[test]
+    fn phase_accumulator_negative_increment_wraps() {
+        let mut acc = PhaseAccumulator::new();
+        for _ in 0..1000 {
+            let p = acc.advance(-0.013);
+            assert!((0.0..1.0).contains(&p));
+        }
+        acc.reset();
+        assert_eq!(acc.phase(), 0.0);
+    }
+
+    const AMP: f32 = 1.5;
+    const OFFSET: f32 = 3.0;
+    const FREQ: f32 = 0.5;
+
+    fn check_oscillator(f: impl Fn(f32) -> f32) {
+        let period = 1.0 / FREQ;
+        for i in 0..2000 {
+            let t = i as f32 * 0.0037;
+            let v = f(t);
+            assert!(
+                (OFFSET - AMP - 1e-5..=OFFSET + AMP + 1e-5).contains(&v),
+                "t = {}: {}",
+                t,
+                v
+            );
+            assert!((f(t + period) - v).abs() < 1e-3, "período em t = {}", t);
+        }
+    }
+
+    #[test]
+    fn oscillate_sin_bounds_and_period() {
+        let f = |t| oscillate_sinf(t, FREQ, AMP, OFFSET);
+        check_oscillator(f);
+        // Pico em um quarto de período, vale em três quartos
+        assert!((f(0.5) - (OFFSET + AMP)).abs() < 1e-5);
+        assert!((f(1.5) - (OFFSET - AMP)).abs() < 1e-5);
+        assert!((f(0.0) - OFFSET).abs() < 1e-6);
+    }
+
+    #[test]
+    fn oscillate_triangle_bounds_and_period() {
+        let f = |t| oscillate_trianglef(t, FREQ, AMP, OFFSET);
+        check_oscillator(f);
+        assert_eq!(f(0.5), OFFSET + AMP);
+        assert_eq!(f(1.5), OFFSET - AMP);
+    }
+
+    #[test]
+    fn oscillate_pulse_bounds_and_duty() {
+        let f = |t| oscillate_pulsef(t, FREQ, AMP, OFFSET, 0.25);
+        check_oscillator(f);
+        // Alto no primeiro quarto do período de 2 unidades
+        assert_eq!(f(0.1), OFFSET + AMP);
+        assert_eq!(f(0.6), OFFSET - AMP);
+        assert_eq!(f(2.1), OFFSET + AMP);
+    }
+}
@@ -0,0 +1,170 @@
+//! # Funções de Janela
+//!
+//! Janelas para análise espectral (DSP) e kernels de filtro.
+//!
+//! Todas usam a convenção **simétrica**: o coeficiente da amostra `i` de
+//! uma janela de `n` amostras usa `i / (n - 1)`, então as duas pontas têm
+//! o mesmo valor. Para a convenção periódica (usada antes de uma FFT),
+//! gere uma janela de `n + 1` amostras e descarte a última.
+
+use crate::consts::TAU;
+use crate::exp::expf;
+use crate::trig::cosf;
+use crate::util::sqf;
+
+// =============================================================================
+// JANELAS COSSENOIDAIS
+// =============================================================================
+
+/// Janela de Hann.
+///
+/// Vale 0 nas pontas e 1 no centro.
+#[inline]
+pub fn hannf(i: u32, n: u32) -> f32 {
+    match phase(i, n) {
+        Some(p) => 0.5 - 0.5 * cosf(p),
+        None => 1.0,
+    }
+}
+
+/// Janela de Hamming.
+///
+/// Vale 0.08 nas pontas e 1 no centro.
+#[inline]
+pub fn hammingf(i: u32, n: u32) -> f32 {
+    match phase(i, n) {
+        Some(p) => 0.54 - 0.46 * cosf(p),
+        None => 1.0,
+    }
+}
+
+/// Janela de Blackman (a0 = 0.42, a1 = 0.5, a2 = 0.08).
+///
+/// Vale ~0 nas pontas e 1 no centro.
+#[inline]
+pub fn blackmanf(i: u32, n: u32) -> f32 {
+    match phase(i, n) {
+        Some(p) => 0.42 - 0.5 * cosf(p) + 0.08 * cosf(2.0 * p),
+        None => 1.0,
+    }
+}
+
+// Fase 2π·i/(n-1); `None` para janelas de 0 ou 1 amostra
+#[inline]
+fn phase(i: u32, n: u32) -> Option<f32> {
+    if n <= 1 {
+        None
+    } else {
+        Some(TAU * i as f32 / (n - 1) as f32)
+    }
+}
+
+// =============================================================================
+// KERNEL GAUSSIANO
+// =============================================================================
+
+/// Preenche `out` com um kernel gaussiano 1D normalizado (soma 1).
+///
+/// O peso da posição `i` é `exp(-x² / 2σ²)` com `x = i - radius`, e
+/// depois todos são divididos pela soma. `out` precisa ter exatamente
+/// `2 * radius + 1` elementos (entra em pânico caso contrário).
+/// `sigma <= 0` gera um impulso (1 no centro, 0 no resto).
+#[inline]
+pub fn gaussian_kernelf(radius: u32, sigma: f32, out: &mut [f32]) {
+    assert_eq!(
+        out.len(),
+        2 * radius as usize + 1,
+        "gaussian_kernelf: slice deve ter 2 * radius + 1 elementos"
+    );
+
+    if sigma <= 0.0 {
+        out.fill(0.0);
+        out[radius as usize] = 1.0;
+        return;
+    }
+
+    let k = -1.0 / (2.0 * sqf(sigma));
+    let mut sum = 0.0;
+    for (i, w) in out.iter_mut().enumerate() {
+        let x = i as f32 - radius as f32;
+        *w = expf(sqf(x) * k);
+        sum += *w;
+    }
+
+    let inv = 1.0 / sum;
+    for w in out.iter_mut() {
+        *w *= inv;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hann_endpoints_center_and_symmetry() {
+        let n = 65;
+        assert!(hannf(0, n).abs() < 1e-6);
+        assert!(hannf(n - 1, n).abs() < 1e-6);
+        assert!((hannf(n / 2, n) - 1.0).abs() < 1e-6);
+
+        for i in 0..n {
+            let a = hannf(i, n);
+            assert!((a - hannf(n - 1 - i, n)).abs() < 1e-6, "i = {i}");
+            assert!((-1e-6..=1.0 + 1e-6).contains(&a));
+        }
+    }
+
+    #[test]
+    fn hamming_and_blackman_are_symmetric() {
+        let n = 32;
+        assert!((hammingf(0, n) - 0.08).abs() < 1e-6);
+        assert!(blackmanf(0, n).abs() < 1e-6);
+        for i in 0..n {
+            assert!((hammingf(i, n) - hammingf(n - 1 - i, n)).abs() < 1e-6);
+            assert!((blackmanf(i, n) - blackmanf(n - 1 - i, n)).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn degenerate_windows_are_one() {
+        assert_eq!(hannf(0, 1), 1.0);
+        assert_eq!(hammingf(0, 0), 1.0);
+        assert_eq!(blackmanf(0, 1), 1.0);
+    }
+
+    #[test]
+    fn gaussian_kernel_is_normalized_symmetric_and_peaked() {
+        let mut k = [0.0; 9];
+        gaussian_kernelf(4, 1.5, &mut k);
+
+        let sum: f32 = k.iter().sum();
+        assert!((sum - 1.0).abs() < 1e-6, "soma {}", sum);
+        for i in 0..4 {
+            assert_eq!(k[i], k[8 - i], "i = {}", i);
+            // Cresce até o centro
+            assert!(k[i] < k[i + 1], "i = {}", i);
+        }
+        // Razão entre vizinhos segue exp(-x²/2σ²)
+        let expected = (-1.0f64 / (2.0 * 1.5 * 1.5)).exp();
+        assert!(((k[3] / k[4]) as f64 - expected).abs() < 1e-5);
+    }
+
+    #[test]
+    fn gaussian_kernel_degenerate_sigma_is_impulse() {
+        let mut k = [0.5; 5];
+        gaussian_kernelf(2, 0.0, &mut k);
+        assert_eq!(k, [0.0, 0.0, 1.0, 0.0, 0.0]);
+
+        let mut single = [0.0; 1];
+        gaussian_kernelf(0, 2.0, &mut single);
+        assert_eq!(single, [1.0]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn gaussian_kernel_rejects_wrong_length() {
+        let mut k = [0.0; 4];
+        gaussian_kernelf(2, 1.0, &mut k);
+    }
+}